@@ -0,0 +1,117 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::env;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, SamplingMode};
+use madarska_metoda::generate::Instance;
+use madarska_metoda::limits::SolveOptions;
+use madarska_metoda::reference::Backend;
+use madarska_metoda::Matrix;
+
+/// Counts live heap bytes so every benchmark can also record the peak memory of one solve
+/// in `target/criterion/peak_memory.csv`.
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(allocated, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const SEED: u64 = 0x6d61_6461_7273_6b61;
+const SIZES: [usize; 7] = [10, 50, 100, 200, 500, 1000, 2000];
+
+/// Rough time one benchmark point may take, slow solves get fewer samples.
+const POINT_TIME: Duration = Duration::from_secs(20);
+
+/// The step solvers are O(n⁴) in the worst case and adversarial instances
+/// reach it, larger sizes would take hours.
+fn max_size(backend: Backend, instance: Instance) -> usize {
+    match (backend, instance) {
+        (Backend::Classic, Instance::Adversarial) => 50,
+        (Backend::Munkres, Instance::Adversarial) => 100,
+        (Backend::Classic, _) => 100,
+        (Backend::Munkres, _) => 200,
+        (Backend::Potentials, _) => 2000,
+    }
+}
+
+fn solve(backend: Backend, matrix: &Matrix) {
+    backend.solve(matrix, None, &SolveOptions::default());
+}
+
+/// Time and peak heap growth in bytes of solving `matrix` once.
+fn solve_once(backend: Backend, matrix: &Matrix) -> (Duration, usize) {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    PEAK.store(before, Ordering::Relaxed);
+    let timer = Instant::now();
+    solve(backend, matrix);
+    (timer.elapsed(), PEAK.load(Ordering::Relaxed) - before)
+}
+
+/// Criterion's output directory, the peak memory table is written next to its reports.
+fn criterion_dir() -> PathBuf {
+    if let Some(home) = env::var_os("CRITERION_HOME") {
+        return PathBuf::from(home);
+    }
+    let target = env::var_os("CARGO_TARGET_DIR").map_or_else(|| PathBuf::from("target"), PathBuf::from);
+    target.join("criterion")
+}
+
+fn solvers(c: &mut Criterion) {
+    let dir = criterion_dir();
+    fs::create_dir_all(&dir).expect("cannot create the criterion directory");
+    let mut memory = File::create(dir.join("peak_memory.csv")).expect("cannot create peak_memory.csv");
+    writeln!(memory, "instance,backend,size,bytes").expect("cannot write peak_memory.csv");
+
+    for instance in Instance::ALL {
+        let mut group = c.benchmark_group(instance.name());
+        for size in SIZES {
+            let matrix = instance.generate(size, SEED);
+
+            for backend in Backend::ALL {
+                if size > max_size(backend, instance) {
+                    continue;
+                }
+                let (elapsed, peak) = solve_once(backend, &matrix);
+                writeln!(memory, "{},{},{},{}", instance.name(), backend.name(), size, peak)
+                    .expect("cannot write peak_memory.csv");
+
+                // Criterion needs at least 10 samples, slower solves get one iteration per sample
+                // and a margin over the measured time so the estimate is not exceeded.
+                let samples = (POINT_TIME.as_secs_f64() / elapsed.as_secs_f64().max(1e-9)) as usize;
+                let samples = samples.clamp(10, 100);
+                group.sample_size(samples);
+                group.sampling_mode(if samples < 100 { SamplingMode::Flat } else { SamplingMode::Auto });
+                group.measurement_time(elapsed.mul_f64(samples as f64 * 1.2).max(Duration::from_secs(5)));
+                group.bench_with_input(BenchmarkId::new(backend.name(), size), &matrix, |b, matrix| {
+                    b.iter_batched(|| matrix.clone(), |matrix| solve(backend, &matrix), BatchSize::LargeInput)
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, solvers);
+criterion_main!(benches);
@@ -0,0 +1,153 @@
+//! Writes the seed corpus of every fuzz target from matrices used in the
+//! crate's tests, run from the `fuzz` directory with
+//! `cargo run --example write_corpus`.
+
+use std::fs;
+use std::path::Path;
+
+use fuzz_input::Input;
+
+const TARGETS: [&str; 2] = ["matrix_new", "solve"];
+
+fn seeds() -> Vec<(&'static str, Vec<Vec<i32>>)> {
+    vec![
+        ("empty", vec![]),
+        ("empty_row", vec![vec![]]),
+        ("ragged", vec![vec![], vec![1, 2]]),
+        ("solve_max", vec![vec![1, 2], vec![2, 4]]),
+        ("reductions", vec![vec![4, 6, 5], vec![7, 2, 9], vec![5, 3, 8]]),
+        ("max_offset", vec![vec![1, 2, 3], vec![6, 4, 5]]),
+        ("negative_padding", vec![vec![-5, -1, -3], vec![-2, -8, -4]]),
+        ("rectangular", vec![vec![4, 6, 5, 3, 1], vec![4, 2, 7, 3, 1], vec![5, 3, 9, 5, 1]]),
+        ("overflow_max", vec![vec![i32::MAX, 0], vec![0, i32::MAX]]),
+        ("overflow_min", vec![vec![i32::MIN, 0], vec![0, 0]]),
+        ("overflow_mixed", vec![vec![i32::MAX, i32::MIN], vec![0, 1]]),
+        (
+            "classic_backtracking",
+            vec![
+                vec![28, 27, 8, 15, 46, 52, 55, 93],
+                vec![51, 6, 19, 18, 97, 26, 9, 31],
+                vec![30, 61, 40, 74, 64, 34, 36, 93],
+                vec![31, 14, 53, 40, 9, 10, 57, 40],
+                vec![85, 21, 27, 77, 78, 24, 24, 84],
+                vec![16, 69, 21, 69, 12, 39, 26, 81],
+                vec![8, 5, 80, 65, 21, 14, 68, 59, 12552, 3328],
+            ],
+        ),
+        ("solve_1", vec![vec![10, 8, 4, 5], vec![6, 2, 12, 3], vec![3, 5, 6, 9], vec![4, 7, 8, 6]]),
+        ("solve_2", vec![vec![1, 5, 7, 1], vec![3, 1, 1, 7], vec![2, 1, 2, 4], vec![1, 3, 1, 3]]),
+        ("solve_3", vec![vec![1, 5, 7, 1], vec![3, 1, 1, 7], vec![2, 1, 4, 1], vec![1, 3, 1, 3]]),
+        (
+            "solve_4",
+            vec![
+                vec![60, 59, 71, 15, 82],
+                vec![21, 54, 63, 30, 92],
+                vec![28, 7, 97, 5, 96],
+                vec![70, 5, 95, 75, 31],
+                vec![41, 64, 55, 85, 59],
+            ],
+        ),
+        (
+            "solve_5",
+            vec![
+                vec![25, 21, 26, 59, 96, 6, 19, 15, 73, 91],
+                vec![74, 64, 13, 10, 10, 49, 87, 33, 91, 47],
+                vec![45, 82, 2, 23, 67, 96, 78, 50, 8, 13],
+                vec![97, 59, 61, 23, 59, 15, 62, 42, 39, 45],
+                vec![74, 95, 78, 44, 86, 11, 25, 34, 86, 15],
+                vec![17, 89, 95, 72, 98, 35, 42, 8, 99, 66],
+                vec![19, 18, 75, 52, 84, 28, 7, 49, 32, 47],
+                vec![53, 52, 46, 34, 80, 5, 70, 34, 29, 27],
+                vec![21, 21, 77, 29, 41, 22, 2, 17, 21, 64],
+                vec![31, 24, 61, 87, 55, 27, 47, 40, 37, 73],
+            ],
+        ),
+        (
+            "solve_6",
+            vec![
+                vec![28, 27, 8, 15, 46, 52, 55, 93],
+                vec![51, 6, 19, 18, 97, 26, 9, 31],
+                vec![30, 61, 40, 74, 64, 34, 36, 93],
+                vec![31, 14, 53, 40, 9, 10, 57, 40],
+                vec![85, 21, 27, 77, 78, 24, 24, 84],
+                vec![16, 69, 21, 69, 12, 39, 26, 81],
+                vec![57, 5, 80, 65, 21, 14, 68, 59],
+                vec![49, 13, 82, 78, 46, 48, 88, 48],
+            ],
+        ),
+        (
+            "solve_9",
+            vec![
+                vec![2, 9, 2, 7, 1],
+                vec![6, 8, 7, 6, 1],
+                vec![4, 6, 5, 3, 1],
+                vec![4, 2, 7, 3, 1],
+                vec![5, 3, 9, 5, 1],
+            ],
+        ),
+        (
+            "solve_11",
+            vec![
+                vec![21, 10, 13, 25, 16, 16, 5],
+                vec![16, 12, 23, 25, 16, 4, 24],
+                vec![14, 13, 10, 23, 22, 24, 28],
+                vec![11, 23, 16, 28, 25, 11, 24],
+                vec![16, 9, 23, 20, 13, 29, 20],
+                vec![4, 17, 9, 14, 11, 12, 24],
+            ],
+        ),
+        (
+            "solve_12",
+            vec![
+                vec![20, 0, 0, 0, 0],
+                vec![0, 24, 0, 0, 0],
+                vec![0, 0, 38, 0, 0],
+                vec![0, 0, 0, 50, 0],
+                vec![0, 0, 0, 0, 48],
+            ],
+        ),
+        (
+            "solve_13",
+            vec![
+                vec![5, 2, 5, 4, 2, 2, 4, 3, 2, 2],
+                vec![4, 2, 2, 3, 2, 4, 3, 5, 2, 2],
+                vec![2, 3, 5, 3, 2, 2, 4, 2, 3, 3],
+                vec![3, 5, 2, 2, 4, 2, 2, 5, 2, 3],
+                vec![5, 2, 4, 4, 4, 4, 3, 5, 5, 2],
+                vec![5, 2, 3, 4, 3, 4, 2, 5, 3, 4],
+                vec![5, 2, 5, 4, 4, 3, 4, 3, 3, 2],
+                vec![4, 4, 3, 2, 2, 4, 3, 2, 3, 4],
+                vec![5, 2, 3, 4, 5, 2, 5, 4, 4, 3],
+                vec![3, 5, 2, 5, 3, 4, 3, 5, 3, 2],
+            ],
+        ),
+        (
+            "solve_14",
+            vec![vec![2, 0, 1, 4, 4], vec![1, 1, 3, 2, 1], vec![2, 1, 3, 4, 1], vec![3, 4, 4, 4, 4], vec![4, 0, 4, 2, 0]],
+        ),
+        (
+            "solve_15",
+            vec![vec![0, 0, 0, 0, 1], vec![1, 1, 0, 0, 2], vec![1, 0, 2, 2, 0], vec![0, 1, 1, 2, 0], vec![1, 2, 2, 2, 1]],
+        ),
+        (
+            "solve_16",
+            vec![vec![2, 1, 0, 0, 0], vec![2, 2, 1, 2, 1], vec![0, 1, 1, 2, 2], vec![2, 2, 2, 0, 1], vec![0, 1, 1, 0, 1]],
+        ),
+    ]
+}
+
+fn main() -> std::io::Result<()> {
+    let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus");
+    for target in TARGETS {
+        let dir = corpus.join(target);
+        fs::create_dir_all(&dir)?;
+        for (name, rows) in seeds() {
+            for maximize in [false, true] {
+                let input = Input { maximize, rows: rows.clone() };
+                let suffix = if maximize { "max" } else { "min" };
+                fs::write(dir.join(format!("{}_{}", name, suffix)), input.encode())?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+#![no_main]
+
+use fuzz_input::Input;
+use libfuzzer_sys::fuzz_target;
+use madarska_metoda::Matrix;
+
+/// Keeps the padded square below a few hundred megabytes.
+const MAX_ROWS: usize = 2048;
+
+fuzz_target!(|data: &[u8]| {
+    let input = Input::decode(data, MAX_ROWS);
+    let matrix = Matrix::new(input.rows.clone());
+
+    let original_columns = input.rows.iter().map(Vec::len).max().unwrap_or(0);
+    let size = input.rows.len().max(original_columns);
+    assert_eq!((size, size), (matrix.rows(), matrix.columns()));
+    assert_eq!((input.rows.len(), original_columns), (matrix.original_rows(), matrix.original_columns()));
+    assert_eq!(size * size, matrix.as_slice().len());
+
+    for (row, values) in matrix.iter_rows().enumerate() {
+        for (col, &value) in values.iter().enumerate() {
+            let expected = input.rows.get(row).and_then(|r| r.get(col)).copied().unwrap_or(0);
+            assert_eq!(expected, value, "cell ({}, {})", row, col);
+            assert_eq!(row >= input.rows.len() || col >= original_columns, matrix.is_dummy(row, col));
+        }
+    }
+});
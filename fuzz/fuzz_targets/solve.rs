@@ -0,0 +1,48 @@
+#![no_main]
+
+use std::convert::TryFrom;
+
+use fuzz_input::Input;
+use libfuzzer_sys::fuzz_target;
+use madarska_metoda::limits::SolveError;
+use madarska_metoda::reference::{cross_check, is_valid_assignment, Backend, MAX_REFERENCE_SIZE};
+use madarska_metoda::{MadarskaMetoda, MadarskaMetodaMunkres, MadarskaMetodaPotentials, Matrix, Objective};
+
+fuzz_target!(|data: &[u8]| {
+    let mut input = Input::decode(data, MAX_REFERENCE_SIZE);
+    for row in &mut input.rows {
+        row.truncate(MAX_REFERENCE_SIZE);
+    }
+    let matrix = Matrix::new(input.rows);
+    let objective = Objective::from(input.maximize);
+
+    let check = cross_check(&matrix, objective);
+    let expected = check.reference.as_ref().unwrap().cost;
+
+    for result in &check.results {
+        match &result.result {
+            Ok(cost) => {
+                assert_eq!(expected, *cost as i64, "{:?} {:?}", result.backend, matrix.to_vec());
+                assert!(is_valid_assignment(&matrix, &result.assignment), "{:?} {:?}", result.backend, result.assignment);
+                let total: i64 = result.assignment.iter().map(|&(row, col)| matrix[row][col] as i64).sum();
+                assert_eq!(expected, total, "{:?}", result.backend);
+            },
+            // The step solvers reduce in `i32` and may overflow on the way to a
+            // result that fits, the potentials only when the result does not.
+            Err(SolveError::Overflow) => assert!(
+                result.backend != Backend::Potentials || i32::try_from(expected).is_err(),
+                "{:?}",
+                matrix.to_vec()
+            ),
+            Err(error) => panic!("{:?} failed with {:?} on {:?}", result.backend, error, matrix.to_vec()),
+        }
+
+        // `solve` reports any error as -1.
+        let solved = match result.backend {
+            Backend::Classic => MadarskaMetoda::new(&matrix).solve(objective),
+            Backend::Munkres => MadarskaMetodaMunkres::new(&matrix).solve(objective),
+            Backend::Potentials => MadarskaMetodaPotentials::new(&matrix).solve(objective),
+        };
+        assert_eq!(*result.result.as_ref().unwrap_or(&-1), solved, "{:?}", result.backend);
+    }
+});
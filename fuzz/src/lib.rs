@@ -0,0 +1,56 @@
+//! Byte format shared by the fuzz targets and the seed corpus.
+//!
+//! The first byte holds flags, bit 0 meaning maximize. Every row follows as a
+//! length byte and that many little-endian `i32` values. A row cut short by the
+//! end of the input keeps the values read so far, so any byte string decodes
+//! to some ragged, possibly empty, matrix.
+//!
+//! Run a target with `cargo +nightly fuzz run solve` from the crate root.
+
+use std::convert::TryInto;
+
+pub struct Input {
+    pub maximize: bool,
+    pub rows: Vec<Vec<i32>>,
+}
+
+impl Input {
+    /// Decodes at most `max_rows` rows, the rest of `data` is ignored.
+    pub fn decode(data: &[u8], max_rows: usize) -> Self {
+        let (flags, mut rest) = match data.split_first() {
+            Some((&flags, rest)) => (flags, rest),
+            None => (0, data),
+        };
+
+        let mut rows = Vec::new();
+        while let Some((&len, tail)) = rest.split_first() {
+            if rows.len() == max_rows {
+                break;
+            }
+            let bytes = tail.len().min(len as usize * 4);
+            let row = tail[..bytes]
+                .chunks_exact(4)
+                .map(|value| i32::from_le_bytes(value.try_into().unwrap()))
+                .collect();
+            rows.push(row);
+            rest = &tail[bytes..];
+        }
+
+        Self {
+            maximize: flags & 1 == 1,
+            rows,
+        }
+    }
+
+    /// Inverse of `decode` for rows of at most 255 values.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = vec![self.maximize as u8];
+        for row in &self.rows {
+            data.push(row.len() as u8);
+            for value in row {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        data
+    }
+}
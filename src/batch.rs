@@ -0,0 +1,63 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::limits::{SolveError, SolveOptions};
+use crate::{MadarskaMetodaPotentials, Matrix, Objective};
+
+/// Cost of one matrix of a batch, `Err` when its total does not fit in `i32`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BatchResult {
+    pub result: Result<i32, SolveError>,
+    pub assignment: Vec<(usize, usize)>,
+}
+
+fn solve_one(mm: &mut MadarskaMetodaPotentials, matrix: &Matrix, objective: Objective) -> BatchResult {
+    mm.reset(matrix);
+    let result = mm.solve_with_options(objective, &SolveOptions::default());
+    BatchResult { result, assignment: mm.assignment() }
+}
+
+/// Solves independent matrices, concurrently when the `parallel` feature is
+/// enabled. Each thread reuses one solver, so the buffers are only allocated
+/// for the largest matrix it sees. Results are returned in the order of `matrices`.
+pub fn solve_batch<O: Into<Objective>>(matrices: &[Matrix], objective: O) -> Vec<BatchResult> {
+    let objective = objective.into();
+    #[cfg(feature = "parallel")]
+    let results = matrices
+        .par_iter()
+        .map_init(MadarskaMetodaPotentials::default, |mm, matrix| solve_one(mm, matrix, objective))
+        .collect();
+    #[cfg(not(feature = "parallel"))]
+    let results = {
+        let mut mm = MadarskaMetodaPotentials::default();
+        matrices.iter().map(|matrix| solve_one(&mut mm, matrix, objective)).collect()
+    };
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_solve() {
+        let matrices = vec![
+            Matrix::new(vec![vec![1, 2], vec![2, 4]]),
+            Matrix::new(vec![vec![4, 6, 5, 3, 1], vec![4, 2, 7, 3, 1], vec![5, 3, 9, 5, 1]]),
+            Matrix::new(vec![]),
+        ];
+
+        let results = solve_batch(&matrices, None);
+        assert_eq!(vec![Ok(4), Ok(6), Ok(0)], results.iter().map(|r| r.result.clone()).collect::<Vec<_>>());
+        assert_eq!(3, results[1].assignment.len());
+        assert!(results[2].assignment.is_empty());
+
+        assert_eq!(Ok(5), solve_batch(&matrices[..1], Some(true))[0].result);
+        let overflow = Matrix::new(vec![vec![i32::MAX, 0], vec![0, i32::MAX]]);
+        assert_eq!(Err(SolveError::Overflow), solve_batch(&[overflow], Some(true))[0].result);
+    }
+}
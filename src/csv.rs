@@ -0,0 +1,266 @@
+use crate::parse::{Delimiter, ParseError};
+use crate::Matrix;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How an empty cell of the table is read.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlankCell {
+    Forbidden,
+    Default(i32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CsvOptions {
+    pub delimiter: Option<Delimiter>,
+    pub header: bool,
+    pub row_labels: bool,
+    pub blank: BlankCell,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: None,
+            header: true,
+            row_labels: true,
+            blank: BlankCell::Forbidden,
+        }
+    }
+}
+
+/// Cost table read from CSV or TSV, the labels are kept on `matrix`.
+/// Forbidden cells are stored as 0 in `matrix` and are only penalized by `cost_matrix`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LabelledMatrix {
+    pub matrix: Matrix,
+    pub forbidden: Vec<(usize, usize)>,
+}
+
+fn split_record(line: &str, delimiter: Delimiter) -> Vec<String> {
+    let separator = match delimiter {
+        Delimiter::Comma => ',',
+        Delimiter::Tab => '\t',
+        Delimiter::Whitespace => return line.split_whitespace().map(str::to_owned).collect(),
+    };
+
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            },
+            '"' => quoted = !quoted,
+            c if c == separator && !quoted => {
+                fields.push(field.trim().to_owned());
+                field.clear();
+            },
+            c => field.push(c),
+        }
+    }
+    fields.push(field.trim().to_owned());
+    fields
+}
+
+fn quote(field: &str, delimiter: Delimiter) -> String {
+    let separator = match delimiter {
+        Delimiter::Tab => '\t',
+        _ => ',',
+    };
+    if field.contains(separator) || field.contains('"') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+impl LabelledMatrix {
+    pub fn read(input: &str, options: &CsvOptions) -> Result<Self, ParseError> {
+        let delimiter = options.delimiter.unwrap_or_else(|| Delimiter::detect(input));
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim().starts_with('#'));
+
+        let label_offset = options.row_labels as usize;
+        let mut column_labels = Vec::new();
+        if options.header {
+            if let Some((_, line)) = lines.next() {
+                column_labels = split_record(line, delimiter).into_iter().skip(label_offset).collect();
+            }
+        }
+
+        let mut row_labels = Vec::new();
+        let mut data: Vec<Vec<i32>> = Vec::new();
+        let mut forbidden = Vec::new();
+        for (index, line) in lines {
+            let mut fields = split_record(line, delimiter).into_iter();
+            if options.row_labels {
+                row_labels.push(fields.next().unwrap_or_default());
+            }
+
+            let mut row = Vec::new();
+            for (column, value) in fields.enumerate() {
+                if value.is_empty() {
+                    match options.blank {
+                        BlankCell::Forbidden => {
+                            forbidden.push((data.len(), column));
+                            row.push(0);
+                        },
+                        BlankCell::Default(v) => row.push(v),
+                    }
+                    continue;
+                }
+                match value.parse::<i32>() {
+                    Ok(v) => row.push(v),
+                    Err(_) => {
+                        return Err(ParseError::InvalidValue {
+                            line: index + 1,
+                            column: column + label_offset + 1,
+                            value,
+                        })
+                    },
+                }
+            }
+
+            let expected = if options.header { column_labels.len() } else { data.first().map_or(row.len(), Vec::len) };
+            if row.len() != expected {
+                return Err(ParseError::RaggedRow { line: index + 1, expected, found: row.len() });
+            }
+            data.push(row);
+        }
+
+        if data.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let matrix = Matrix::new(data);
+        if !options.header {
+            column_labels = (1..=matrix.original_columns()).map(|c| c.to_string()).collect();
+        }
+        if !options.row_labels {
+            row_labels = (1..=matrix.original_rows()).map(|r| r.to_string()).collect();
+        }
+
+        Ok(Self {
+            matrix: matrix.with_labels(row_labels, column_labels).expect("ragged rows are rejected above"),
+            forbidden,
+        })
+    }
+
+    /// Matrix for the solvers where forbidden cells cost more (or, when
+    /// maximizing, less) than any complete assignment of allowed cells.
+    pub fn cost_matrix(&self, maximize: bool) -> Result<Matrix, ParseError> {
+        let mut matrix = self.matrix.clone();
+        if self.forbidden.is_empty() {
+            return Ok(matrix);
+        }
+
+        let mut penalty: i32 = 1;
+        for row in self.matrix.iter_rows() {
+            for &value in row {
+                penalty = penalty.checked_add(value.checked_abs().ok_or(ParseError::Overflow)?).ok_or(ParseError::Overflow)?;
+            }
+        }
+        if maximize {
+            penalty = -penalty;
+        }
+
+        for &(row, col) in &self.forbidden {
+            matrix[row][col] = penalty;
+        }
+        Ok(matrix)
+    }
+
+    pub fn is_feasible(&self, assignment: &[(usize, usize)]) -> bool {
+        assignment.iter().all(|cell| !self.forbidden.contains(cell))
+    }
+
+    /// Writes the assignment as `row,column,cost` records using the table labels.
+    pub fn write_assignment(&self, assignment: &[(usize, usize)], delimiter: Delimiter) -> String {
+        let separator = match delimiter {
+            Delimiter::Tab => "\t",
+            _ => ",",
+        };
+
+        let mut out = ["row", "column", "cost"].join(separator);
+        out.push('\n');
+        for &(row, col) in assignment {
+            let record = [
+                quote(self.matrix.row_label(row).unwrap_or_default(), delimiter),
+                quote(self.matrix.column_label(col).unwrap_or_default(), delimiter),
+                self.matrix[row][col].to_string(),
+            ];
+            out.push_str(&record.join(separator));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MadarskaMetodaMunkres;
+
+    const TABLE: &str = "\
+worker,\"Task, first\",Task 2,Task 3
+Ana,4,,8
+Ivan,2,6,
+Marko,3,7,1
+";
+
+    #[test]
+    fn read_labelled() {
+        let table = LabelledMatrix::read(TABLE, &CsvOptions::default()).unwrap();
+
+        assert_eq!(Some(vec!["Ana".to_owned(), "Ivan".to_owned(), "Marko".to_owned()]), table.matrix.row_labels);
+        assert_eq!(Some("Task, first"), table.matrix.column_label(0));
+        assert_eq!(Some("Task 3"), table.matrix.column_label(2));
+        assert_eq!(vec![(0, 1), (1, 2)], table.forbidden);
+        assert_eq!(vec![vec![4, 0, 8], vec![2, 6, 0], vec![3, 7, 1]], table.matrix.to_vec());
+
+        let options = CsvOptions { blank: BlankCell::Default(9), ..CsvOptions::default() };
+        let table = LabelledMatrix::read(&TABLE.replace(',', "\t").replace("\"Task\t first\"", "Task 1"), &options).unwrap();
+        assert!(table.forbidden.is_empty());
+        assert_eq!(vec![vec![4, 9, 8], vec![2, 6, 9], vec![3, 7, 1]], table.matrix.to_vec());
+    }
+
+    #[test]
+    fn read_unlabelled() {
+        let options = CsvOptions { header: false, row_labels: false, ..CsvOptions::default() };
+        let table = LabelledMatrix::read("1,2\n3,4\n", &options).unwrap();
+
+        assert_eq!(Some("2"), table.matrix.row_label(1));
+        assert_eq!(Some("1"), table.matrix.column_label(0));
+        assert_eq!(
+            Err(ParseError::RaggedRow { line: 2, expected: 2, found: 1 }),
+            LabelledMatrix::read("1,2\n3\n", &options)
+        );
+    }
+
+    #[test]
+    fn solve_and_write() {
+        let table = LabelledMatrix::read(TABLE, &CsvOptions::default()).unwrap();
+
+        let mut mm = MadarskaMetodaMunkres::new(table.cost_matrix(false).unwrap());
+        assert_eq!(11, mm.solve(None));
+        let assignment = mm.assignment();
+        assert!(table.is_feasible(&assignment));
+        assert_eq!(Some("Marko".to_owned()), mm.labelled_assignment()[2].row_label);
+        assert_eq!(
+            "row,column,cost\nAna,\"Task, first\",4\nIvan,Task 2,6\nMarko,Task 3,1\n",
+            table.write_assignment(&assignment, Delimiter::Comma)
+        );
+
+        let mut mm = MadarskaMetodaMunkres::new(table.cost_matrix(true).unwrap());
+        mm.solve(Some(true));
+        assert!(table.is_feasible(&mm.assignment()));
+    }
+}
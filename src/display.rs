@@ -0,0 +1,179 @@
+use std::fmt;
+
+use crate::{MadarskaMetoda, MadarskaMetodaMunkres, Matrix, MunkresSnapshot};
+
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.as_slice().iter().map(|v| v.to_string().len()).max().unwrap_or(0);
+        for row in self.iter_rows() {
+            let values: Vec<String> = row.iter().map(|v| format!("{:>width$}", v, width = width)).collect();
+            writeln!(f, "{}", values.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Matrix renderer that overlays an assignment mask, covered lines and padded
+/// dummy cells. Starred zeros are marked with `*`, primed zeros with `'`,
+/// covered lines with `x` and dummy cells are put in parentheses.
+pub struct AnnotatedMatrix<'a> {
+    matrix: &'a Matrix,
+    mask: Option<&'a Matrix>,
+    crossed_rows: Vec<bool>,
+    crossed_columns: Vec<bool>,
+}
+
+impl<'a> AnnotatedMatrix<'a> {
+    pub fn new(matrix: &'a Matrix) -> Self {
+        Self {
+            matrix,
+            mask: None,
+            crossed_rows: vec![false; matrix.rows()],
+            crossed_columns: vec![false; matrix.columns()],
+        }
+    }
+
+    pub fn mask(mut self, mask: &'a Matrix) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    pub fn crossed_rows<T: PartialEq + Default>(mut self, crossed_rows: &[T]) -> Self {
+        self.crossed_rows = crossed_rows.iter().map(|c| *c != T::default()).collect();
+        self
+    }
+
+    pub fn crossed_columns<T: PartialEq + Default>(mut self, crossed_columns: &[T]) -> Self {
+        self.crossed_columns = crossed_columns.iter().map(|c| *c != T::default()).collect();
+        self
+    }
+
+    fn cell(&self, row: usize, col: usize) -> String {
+        let value = self.matrix[row][col];
+        let mark = match self.mask.map(|mask| mask[row][col]) {
+            Some(1) => "*",
+            Some(2) => "'",
+            _ => " ",
+        };
+        if self.matrix.is_dummy(row, col) {
+            format!("({}){}", value, mark)
+        } else {
+            format!("{}{}", value, mark)
+        }
+    }
+
+    fn is_crossed(crossed: &[bool], index: usize) -> bool {
+        crossed.get(index).copied().unwrap_or(false)
+    }
+}
+
+impl<'a> fmt::Display for AnnotatedMatrix<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut cells = Vec::with_capacity(self.matrix.rows());
+        for row in 0..self.matrix.rows() {
+            cells.push((0..self.matrix.columns()).map(|col| self.cell(row, col)).collect::<Vec<String>>());
+        }
+        let width = cells.iter().flatten().map(|c| c.chars().count()).max().unwrap_or(0);
+
+        if self.crossed_columns.iter().any(|&c| c) {
+            let marks: Vec<String> = (0..self.matrix.columns())
+                .map(|col| {
+                    let mark = if Self::is_crossed(&self.crossed_columns, col) { "x " } else { "  " };
+                    format!("{:>width$}", mark, width = width)
+                })
+                .collect();
+            writeln!(f, "{}", marks.join(" ").trim_end())?;
+        }
+
+        for (row, values) in cells.iter().enumerate() {
+            let values: Vec<String> = values.iter().map(|v| format!("{:>width$}", v, width = width)).collect();
+            let line = values.join(" ");
+            if Self::is_crossed(&self.crossed_rows, row) {
+                writeln!(f, "{} x", line)?;
+            } else {
+                writeln!(f, "{}", line.trim_end())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Matrix {
+    pub fn annotated(&self) -> AnnotatedMatrix<'_> {
+        AnnotatedMatrix::new(self)
+    }
+}
+
+impl MunkresSnapshot {
+    pub fn annotated(&self) -> AnnotatedMatrix<'_> {
+        self.calculating_matrix
+            .annotated()
+            .mask(&self.assignment_mask)
+            .crossed_rows(&self.crossed_rows)
+            .crossed_columns(&self.crossed_columns)
+    }
+}
+
+impl MadarskaMetodaMunkres {
+    pub fn annotated(&self) -> AnnotatedMatrix<'_> {
+        self.calculating_matrix
+            .annotated()
+            .mask(&self.assignment_mask)
+            .crossed_rows(&self.crossed_rows)
+            .crossed_columns(&self.crossed_columns)
+    }
+}
+
+impl MadarskaMetoda {
+    pub fn annotated(&self) -> AnnotatedMatrix<'_> {
+        let mut annotated = self
+            .calculating_matrix
+            .annotated()
+            .crossed_rows(&self.crossed_rows)
+            .crossed_columns(&self.crossed_columns);
+        if self.assignment_mask.rows() == self.calculating_matrix.rows() {
+            annotated = annotated.mask(&self.assignment_mask);
+        }
+        annotated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_matrix() {
+        let matrica = Matrix::new(vec![
+            vec![10, 8, 4],
+            vec![ 6, 2, 12],
+            vec![ 3, 5, 6],
+        ]);
+
+        assert_eq!("10  8  4\n 6  2 12\n 3  5  6\n", matrica.to_string());
+    }
+
+    #[test]
+    fn annotated_matrix() {
+        let matrica = Matrix::new(vec![
+            vec![0, 4],
+            vec![3, 0],
+            vec![0, 5],
+        ]);
+        let mut mask = Matrix::new_empty(3, 3);
+        mask[0][0] = 1;
+        mask[2][0] = 2;
+
+        let rendered = matrica
+            .annotated()
+            .mask(&mask)
+            .crossed_rows(&[0, 0, 1])
+            .crossed_columns(&[true, false, false])
+            .to_string();
+
+        assert_eq!(
+            "  x\n  0*   4  (0)\n  3    0  (0)\n  0'   5  (0)  x\n",
+            rendered
+        );
+    }
+}
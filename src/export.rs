@@ -0,0 +1,409 @@
+use crate::limits::SolveError;
+use crate::{MadarskaMetodaMunkres, Matrix, MunkresSnapshot, Objective};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    Croatian,
+    English,
+}
+
+impl Language {
+    fn starting_matrix(self) -> &'static str {
+        match self {
+            Language::Croatian => "Početna matrica",
+            Language::English => "Starting matrix",
+        }
+    }
+
+    fn max_offset(self) -> &'static str {
+        match self {
+            Language::Croatian => "Maksimizacija: svaka vrijednost matrice je oduzeta od najveće vrijednosti",
+            Language::English => "Maximization: every matrix value is subtracted from the largest value",
+        }
+    }
+
+    /// Step 1 reduces columns instead of rows when the matrix has more rows
+    /// than columns.
+    fn step(self, step: usize, columns_reduced: bool) -> &'static str {
+        match (self, step) {
+            (Language::Croatian, 1) if columns_reduced => "Oduzimanje minimuma stupaca",
+            (Language::Croatian, 1) => "Oduzimanje minimuma redaka",
+            (Language::Croatian, 2) => "Označavanje nezavisnih nula",
+            (Language::Croatian, 3) => "Pokrivanje stupaca s označenim nulama",
+            (Language::Croatian, 4) => "Traženje nepokrivenih nula",
+            (Language::Croatian, 5) => "Zamjena nula duž puta",
+            (Language::Croatian, 6) => "Korekcija najmanjom nepokrivenom vrijednosti",
+            (Language::English, 1) if columns_reduced => "Column reduction",
+            (Language::English, 1) => "Row reduction",
+            (Language::English, 2) => "Starring independent zeros",
+            (Language::English, 3) => "Covering columns with starred zeros",
+            (Language::English, 4) => "Priming uncovered zeros",
+            (Language::English, 5) => "Augmenting along the path",
+            (Language::English, 6) => "Adjusting by the smallest uncovered value",
+            _ => "",
+        }
+    }
+
+    fn step_label(self) -> &'static str {
+        match self {
+            Language::Croatian => "Korak",
+            Language::English => "Step",
+        }
+    }
+
+    fn path(self) -> &'static str {
+        match self {
+            Language::Croatian => "Put",
+            Language::English => "Path",
+        }
+    }
+
+    fn assignment(self) -> &'static str {
+        match self {
+            Language::Croatian => "Optimalni raspored",
+            Language::English => "Optimal assignment",
+        }
+    }
+
+    fn cost(self) -> &'static str {
+        match self {
+            Language::Croatian => "Ukupni trošak",
+            Language::English => "Total cost",
+        }
+    }
+}
+
+enum Cell {
+    Value(i32),
+    Starred(i32),
+    Primed(i32),
+    Boxed(i32),
+}
+
+struct Table {
+    cells: Vec<Vec<Cell>>,
+    crossed_rows: Vec<bool>,
+    crossed_columns: Vec<bool>,
+}
+
+impl Table {
+    fn plain(matrix: &Matrix) -> Self {
+        Self {
+            cells: matrix.iter_rows().map(|row| row.iter().map(|&v| Cell::Value(v)).collect()).collect(),
+            crossed_rows: vec![false; matrix.rows()],
+            crossed_columns: vec![false; matrix.columns()],
+        }
+    }
+
+    fn from_snapshot(snapshot: &MunkresSnapshot) -> Self {
+        let mut cells = Vec::with_capacity(snapshot.calculating_matrix.rows());
+        for row in 0..snapshot.calculating_matrix.rows() {
+            let mut cell_row = Vec::with_capacity(snapshot.calculating_matrix.columns());
+            for col in 0..snapshot.calculating_matrix.columns() {
+                let value = snapshot.calculating_matrix[row][col];
+                cell_row.push(match snapshot.assignment_mask[row][col] {
+                    1 => Cell::Starred(value),
+                    2 => Cell::Primed(value),
+                    _ => Cell::Value(value),
+                });
+            }
+            cells.push(cell_row);
+        }
+
+        Self {
+            cells,
+            crossed_rows: snapshot.crossed_rows.iter().map(|&c| c == 1).collect(),
+            crossed_columns: snapshot.crossed_columns.iter().map(|&c| c == 1).collect(),
+        }
+    }
+
+    fn assignment(matrix: &Matrix, assignment_mask: &Matrix) -> Self {
+        let mut table = Self::plain(matrix);
+        for row in 0..matrix.rows() {
+            for col in 0..matrix.columns() {
+                if assignment_mask[row][col] == 1 {
+                    table.cells[row][col] = Cell::Boxed(matrix[row][col]);
+                }
+            }
+        }
+        table
+    }
+
+    fn has_crossed(&self) -> bool {
+        self.crossed_rows.iter().any(|&c| c) || self.crossed_columns.iter().any(|&c| c)
+    }
+}
+
+/// Worked solution of an assignment problem, produced by stepping through the
+/// Munkres solver, that can be rendered as LaTeX or Markdown. Building one
+/// fails with `SolveError::Overflow` when the values overflow a step.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WorkedSolution {
+    pub starting_matrix: Matrix,
+    pub maximize: bool,
+    pub steps: Vec<MunkresSnapshot>,
+    pub result: i32,
+}
+
+impl WorkedSolution {
+    pub fn new<O: Into<Objective>>(starting_matrix: &Matrix, objective: O) -> Result<Self, SolveError> {
+        let objective = objective.into();
+        let mut munkres = MadarskaMetodaMunkres::new(starting_matrix);
+        let steps: Vec<MunkresSnapshot> = munkres.steps(objective).collect::<Result<_, _>>()?;
+        let result = munkres.result().ok_or(SolveError::Overflow)?;
+
+        Ok(Self {
+            starting_matrix: starting_matrix.clone(),
+            maximize: objective.is_maximize(),
+            steps,
+            result,
+        })
+    }
+
+    fn final_assignment(&self) -> Option<&Matrix> {
+        self.steps.last().map(|snapshot| &snapshot.assignment_mask)
+    }
+
+    fn selected_costs(&self) -> Vec<i32> {
+        let mut costs = Vec::new();
+        if let Some(mask) = self.final_assignment() {
+            for row in 0..self.starting_matrix.rows() {
+                for col in 0..self.starting_matrix.columns() {
+                    if mask[row][col] == 1 && !self.starting_matrix.is_dummy(row, col) {
+                        costs.push(self.starting_matrix[row][col]);
+                    }
+                }
+            }
+        }
+        costs
+    }
+
+    pub fn to_latex(&self, language: Language) -> String {
+        let mut out = String::new();
+
+        out.push_str(&format!("\\paragraph{{{}}}\n", language.starting_matrix()));
+        out.push_str(&latex_table(&Table::plain(&self.starting_matrix)));
+        if self.maximize {
+            out.push_str(&format!("{}.\n\n", language.max_offset()));
+        }
+
+        for (i, snapshot) in self.steps.iter().enumerate() {
+            out.push_str(&format!(
+                "\\paragraph{{{} {}: {}}}\n",
+                language.step_label(),
+                i + 1,
+                language.step(snapshot.step, reduces_columns(&snapshot.calculating_matrix))
+            ));
+            out.push_str(&latex_table(&Table::from_snapshot(snapshot)));
+            if !snapshot.path.is_empty() {
+                out.push_str(&format!("{}: ${}$\n\n", language.path(), path_text(&snapshot.path, " \\to ")));
+            }
+        }
+
+        if let Some(mask) = self.final_assignment() {
+            out.push_str(&format!("\\paragraph{{{}}}\n", language.assignment()));
+            out.push_str(&latex_table(&Table::assignment(&self.starting_matrix, mask)));
+        }
+        out.push_str(&format!("{}: ${}$\n", language.cost(), cost_text(&self.selected_costs(), self.result)));
+
+        out
+    }
+
+    pub fn to_markdown(&self, language: Language) -> String {
+        let mut out = String::new();
+
+        out.push_str(&format!("### {}\n\n", language.starting_matrix()));
+        out.push_str(&markdown_table(&Table::plain(&self.starting_matrix)));
+        if self.maximize {
+            out.push_str(&format!("{}.\n\n", language.max_offset()));
+        }
+
+        for (i, snapshot) in self.steps.iter().enumerate() {
+            out.push_str(&format!(
+                "### {} {}: {}\n\n",
+                language.step_label(),
+                i + 1,
+                language.step(snapshot.step, reduces_columns(&snapshot.calculating_matrix))
+            ));
+            out.push_str(&markdown_table(&Table::from_snapshot(snapshot)));
+            if !snapshot.path.is_empty() {
+                out.push_str(&format!("{}: {}\n\n", language.path(), path_text(&snapshot.path, " → ")));
+            }
+        }
+
+        if let Some(mask) = self.final_assignment() {
+            out.push_str(&format!("### {}\n\n", language.assignment()));
+            out.push_str(&markdown_table(&Table::assignment(&self.starting_matrix, mask)));
+        }
+        out.push_str(&format!("{}: {}\n", language.cost(), cost_text(&self.selected_costs(), self.result)));
+
+        out
+    }
+}
+
+/// Same choice as the first step of `MadarskaMetodaMunkres`.
+fn reduces_columns(matrix: &Matrix) -> bool {
+    matrix.rows() > matrix.columns()
+}
+
+fn path_text(path: &[(usize, usize)], separator: &str) -> String {
+    path.iter()
+        .map(|(row, col)| format!("({}, {})", row + 1, col + 1))
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+fn cost_text(costs: &[i32], result: i32) -> String {
+    if costs.is_empty() {
+        return result.to_string();
+    }
+    let sum = costs.iter().map(|c| c.to_string()).collect::<Vec<String>>().join(" + ");
+    format!("{} = {}", sum, result)
+}
+
+fn latex_table(table: &Table) -> String {
+    let columns = table.crossed_columns.len();
+    let crossed = table.has_crossed();
+    let mut out = String::new();
+
+    out.push_str("\\begin{tabular}{");
+    out.push_str(&"|c".repeat(columns));
+    out.push('|');
+    if crossed {
+        out.push('c');
+    }
+    out.push_str("}\n");
+
+    if crossed {
+        let marks: Vec<&str> = table.crossed_columns.iter().map(|&c| if c { "$\\times$" } else { "" }).collect();
+        out.push_str(&marks.join(" & "));
+        out.push_str(" & \\\\\n");
+    }
+    out.push_str("\\hline\n");
+
+    for (row, cells) in table.cells.iter().enumerate() {
+        let values: Vec<String> = cells
+            .iter()
+            .map(|cell| match cell {
+                Cell::Value(v) => format!("${}$", v),
+                Cell::Starred(v) => format!("${}^*$", v),
+                Cell::Primed(v) => format!("${}'$", v),
+                Cell::Boxed(v) => format!("$\\boxed{{{}}}$", v),
+            })
+            .collect();
+        out.push_str(&values.join(" & "));
+        if crossed {
+            out.push_str(if table.crossed_rows[row] { " & $\\times$" } else { " & " });
+        }
+        out.push_str(" \\\\\n\\hline\n");
+    }
+
+    out.push_str("\\end{tabular}\n\n");
+    out
+}
+
+fn markdown_table(table: &Table) -> String {
+    let crossed = table.has_crossed();
+    let mut out = String::new();
+
+    out.push('|');
+    for &c in &table.crossed_columns {
+        out.push_str(if c { " × |" } else { "   |" });
+    }
+    if crossed {
+        out.push_str("   |");
+    }
+    out.push('\n');
+
+    out.push('|');
+    for _ in 0..table.crossed_columns.len() + crossed as usize {
+        out.push_str("---|");
+    }
+    out.push('\n');
+
+    for (row, cells) in table.cells.iter().enumerate() {
+        out.push('|');
+        for cell in cells {
+            let value = match cell {
+                Cell::Value(v) => v.to_string(),
+                Cell::Starred(v) => format!("{}*", v),
+                Cell::Primed(v) => format!("{}'", v),
+                Cell::Boxed(v) => format!("[{}]", v),
+            };
+            out.push_str(&format!(" {} |", value));
+        }
+        if crossed {
+            out.push_str(if table.crossed_rows[row] { " × |" } else { "   |" });
+        }
+        out.push('\n');
+    }
+
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrica() -> Matrix {
+        Matrix::new(vec![
+            vec![1, 2, 3],
+            vec![2, 4, 6],
+            vec![3, 6, 9],
+        ])
+    }
+
+    #[test]
+    fn latex_export() {
+        let solution = WorkedSolution::new(&matrica(), None).unwrap();
+        let latex = solution.to_latex(Language::English);
+
+        assert_eq!(10, solution.result);
+        assert!(latex.starts_with("\\paragraph{Starting matrix}\n\\begin{tabular}{|c|c|c|}\n"));
+        assert!(latex.contains("\\paragraph{Step 1: Row reduction}"));
+        assert!(latex.contains("$0^*$"));
+        assert!(latex.contains("$\\times$"));
+        assert_eq!(3, latex.matches("\\boxed").count());
+        assert!(latex.ends_with("Total cost: $3 + 4 + 3 = 10$\n"));
+    }
+
+    #[test]
+    fn markdown_export() {
+        let solution = WorkedSolution::new(&matrica(), Some(true)).unwrap();
+        let markdown = solution.to_markdown(Language::Croatian);
+
+        assert!(markdown.starts_with("### Početna matrica\n\n|   |   |   |\n|---|---|---|\n| 1 | 2 | 3 |\n"));
+        assert!(markdown.contains("Maksimizacija"));
+        assert!(markdown.contains("### Korak 1: Oduzimanje minimuma redaka"));
+        assert_eq!(3, markdown.matches('[').count());
+        assert!(markdown.ends_with("Ukupni trošak: 1 + 4 + 9 = 14\n"));
+    }
+
+    #[test]
+    fn reduction_direction() {
+        let tall = Matrix::new_rectangular(vec![vec![4, 6], vec![4, 2], vec![5, 3]]);
+        let markdown = WorkedSolution::new(&tall, None).unwrap().to_markdown(Language::English);
+        assert!(markdown.contains("### Step 1: Column reduction"));
+        let wide = Matrix::new_rectangular(vec![vec![4, 4, 5], vec![6, 2, 3]]);
+        let markdown = WorkedSolution::new(&wide, None).unwrap().to_markdown(Language::Croatian);
+        assert!(markdown.contains("### Korak 1: Oduzimanje minimuma redaka"));
+    }
+
+    #[test]
+    fn padded_export() {
+        let matrica = Matrix::new(vec![vec![4, 6, 5], vec![4, 2, 7]]);
+        let latex = WorkedSolution::new(&matrica, None).unwrap().to_latex(Language::English);
+        assert!(latex.ends_with("Total cost: $4 + 2 = 6$\n"));
+    }
+
+    #[test]
+    fn overflow_export() {
+        let matrica = Matrix::new(vec![vec![i32::MIN, 5], vec![5, 0]]);
+        assert!(matches!(WorkedSolution::new(&matrica, Objective::Maximize), Err(SolveError::Overflow)));
+        assert!(matches!(WorkedSolution::new(&matrica, None), Err(SolveError::Overflow)));
+    }
+}
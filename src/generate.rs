@@ -0,0 +1,149 @@
+use crate::Matrix;
+
+/// Structure of a generated instance, see the `Generator` method of the same name.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instance {
+    Uniform,
+    LowRank,
+    Geometric,
+    ManyTies,
+    Adversarial,
+}
+
+impl Instance {
+    pub const ALL: [Instance; 5] = [
+        Instance::Uniform,
+        Instance::LowRank,
+        Instance::Geometric,
+        Instance::ManyTies,
+        Instance::Adversarial,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Instance::Uniform => "uniform",
+            Instance::LowRank => "low_rank",
+            Instance::Geometric => "geometric",
+            Instance::ManyTies => "many_ties",
+            Instance::Adversarial => "adversarial",
+        }
+    }
+
+    /// Square instance of `size` with default parameters for benchmarks.
+    pub fn generate(self, size: usize, seed: u64) -> Matrix {
+        let mut generator = Generator::new(seed);
+        match self {
+            Instance::Uniform => generator.uniform(size, size, 1000),
+            Instance::LowRank => generator.low_rank(size, 3, 30),
+            Instance::Geometric => generator.geometric(size, 1000),
+            Instance::ManyTies => generator.many_ties(size, 4),
+            Instance::Adversarial => Generator::adversarial(size),
+        }
+    }
+}
+
+/// Seeded SplitMix64 generator, the same seed always gives the same matrices
+/// on every platform.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Value in `0..=max`.
+    fn value(&mut self, max: i32) -> i32 {
+        let bound = max.max(0) as u64 + 1;
+        (self.next_u64() % bound) as i32
+    }
+
+    fn matrix<F: FnMut(&mut Self, usize, usize) -> i32>(&mut self, rows: usize, columns: usize, mut cell: F) -> Matrix {
+        let data = (0..rows)
+            .map(|row| (0..columns).map(|col| cell(self, row, col)).collect())
+            .collect();
+        Matrix::new(data)
+    }
+
+    /// Independent values in `0..=max`.
+    pub fn uniform(&mut self, rows: usize, columns: usize, max: i32) -> Matrix {
+        self.matrix(rows, columns, |g, _, _| g.value(max))
+    }
+
+    /// Product of a `size × rank` and a `rank × size` matrix with factors in
+    /// `0..=max`, rows are linear combinations of only `rank` patterns.
+    pub fn low_rank(&mut self, size: usize, rank: usize, max: i32) -> Matrix {
+        let left: Vec<Vec<i32>> = (0..size).map(|_| (0..rank).map(|_| self.value(max)).collect()).collect();
+        let right: Vec<Vec<i32>> = (0..rank).map(|_| (0..size).map(|_| self.value(max)).collect()).collect();
+        self.matrix(size, size, |_, row, col| (0..rank).map(|k| left[row][k] * right[k][col]).sum())
+    }
+
+    /// Rounded distances between `size` workers and `size` tasks placed
+    /// uniformly in an `extent × extent` square.
+    pub fn geometric(&mut self, size: usize, extent: i32) -> Matrix {
+        let workers: Vec<(i32, i32)> = (0..size).map(|_| (self.value(extent), self.value(extent))).collect();
+        let tasks: Vec<(i32, i32)> = (0..size).map(|_| (self.value(extent), self.value(extent))).collect();
+        self.matrix(size, size, |_, row, col| {
+            let dx = (workers[row].0 - tasks[col].0) as f64;
+            let dy = (workers[row].1 - tasks[col].1) as f64;
+            dx.hypot(dy).round() as i32
+        })
+    }
+
+    /// Values drawn from only `distinct` levels, so most rows have many equal minima.
+    pub fn many_ties(&mut self, size: usize, distinct: i32) -> Matrix {
+        self.uniform(size, size, distinct - 1)
+    }
+
+    /// `(row + 1) * (col + 1)`, every row reduces to a different multiple of
+    /// the same pattern and the step solvers need many adjustments to finish.
+    pub fn adversarial(size: usize) -> Matrix {
+        let data = (0..size)
+            .map(|row| (0..size).map(|col| ((row + 1) * (col + 1)) as i32).collect())
+            .collect();
+        Matrix::new(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MadarskaMetodaPotentials;
+
+    #[test]
+    fn seeded_generators() {
+        assert_eq!(Generator::new(7).uniform(4, 6, 9), Generator::new(7).uniform(4, 6, 9));
+        assert_ne!(Generator::new(7).uniform(4, 6, 9), Generator::new(8).uniform(4, 6, 9));
+
+        let uniform = Generator::new(1).uniform(3, 5, 9);
+        assert_eq!((5, 3, 5), (uniform.rows(), uniform.original_rows(), uniform.original_columns()));
+        assert!(uniform.as_slice().iter().all(|v| (0..=9).contains(v)));
+
+        let ties = Generator::new(2).many_ties(20, 3);
+        assert!(ties.as_slice().iter().all(|v| (0..3).contains(v)));
+
+        let geometric = Generator::new(3).geometric(10, 100);
+        assert!(geometric.as_slice().iter().all(|v| (0..=142).contains(v)));
+
+        assert_eq!(vec![vec![1, 2, 3], vec![2, 4, 6], vec![3, 6, 9]], Generator::adversarial(3).to_vec());
+
+        for instance in Instance::ALL {
+            let matrica = instance.generate(12, 42);
+            assert_eq!((12, 12), (matrica.rows(), matrica.columns()), "{}", instance.name());
+            assert_eq!(matrica, instance.generate(12, 42));
+            let mut mm = MadarskaMetodaPotentials::new(&matrica);
+            mm.solve(None);
+            assert_eq!(12, mm.assignment().len());
+        }
+    }
+}
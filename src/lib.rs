@@ -1,148 +1,366 @@
+pub mod batch;
+pub mod csv;
+pub mod display;
+pub mod export;
+pub mod generate;
+pub mod limits;
+pub mod objective;
+pub mod parse;
+pub mod potentials;
+pub mod reference;
+mod reduce;
+pub mod stats;
+pub mod view;
+use std::convert::TryFrom;
+
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut};
+use std::slice::{Chunks, Iter};
 use std::time::Instant;
 
+use limits::{reduction_bound, SolveError, SolveOptions};
+pub use objective::Objective;
+pub use potentials::MadarskaMetodaPotentials;
+pub use view::MatrixView;
+use stats::{Phase, SolveStats};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 pub enum Position {
     Row,
     Column,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(try_from = "RawMatrix"))]
 pub struct Matrix {
-    pub rows: usize,
-    pub columns: usize,
-    pub matrix: Vec<Vec<i32>>,
+    rows: usize,
+    columns: usize,
+    data: Vec<i32>,
+    original_rows: usize,
+    original_columns: usize,
+    pub row_labels: Option<Vec<String>>,
+    pub column_labels: Option<Vec<String>>,
+}
+
+/// Deserialized fields of a `Matrix` before they are checked to fit together.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawMatrix {
+    rows: usize,
+    columns: usize,
+    data: Vec<i32>,
+    original_rows: usize,
+    original_columns: usize,
+    row_labels: Option<Vec<String>>,
+    column_labels: Option<Vec<String>>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawMatrix> for Matrix {
+    type Error = String;
+
+    fn try_from(raw: RawMatrix) -> Result<Self, Self::Error> {
+        if raw.rows.checked_mul(raw.columns) != Some(raw.data.len()) {
+            return Err("Data length must be rows * columns".to_owned());
+        }
+        if raw.original_rows > raw.rows || raw.original_columns > raw.columns {
+            return Err("Original dimensions must fit in the matrix".to_owned());
+        }
+        if raw.row_labels.as_ref().is_some_and(|labels| labels.len() != raw.original_rows)
+            || raw.column_labels.as_ref().is_some_and(|labels| labels.len() != raw.original_columns)
+        {
+            return Err("Every original row and column needs exactly one label".to_owned());
+        }
+        Ok(Self {
+            rows: raw.rows,
+            columns: raw.columns,
+            data: raw.data,
+            original_rows: raw.original_rows,
+            original_columns: raw.original_columns,
+            row_labels: raw.row_labels,
+            column_labels: raw.column_labels,
+        })
+    }
+}
+
+/// Assigned cell of a solved matrix together with its cost and labels.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Assignment {
+    pub row: usize,
+    pub column: usize,
+    pub cost: i32,
+    pub row_label: Option<String>,
+    pub column_label: Option<String>,
 }
 
 impl Matrix {
     pub fn new_empty(row: usize, column: usize) -> Self {
-        let mut new_matrix: Vec<Vec<i32>> = Vec::with_capacity(row);
-        for _ in 0..row {
-            new_matrix.push(vec![0;column]);
-        }
         Self {
             rows: row,
             columns: column,
-            matrix: new_matrix,
+            data: vec![0; row * column],
+            original_rows: row,
+            original_columns: column,
+            row_labels: None,
+            column_labels: None,
         }
     }
     
+    /// Builds a square matrix from rows of values, short rows and the missing
+    /// rows or columns of a rectangular input are padded with zeros.
     pub fn new(data: Vec<Vec<i32>>) -> Self {
-        let mut new_matrix = Self {
-            rows: data.len(),
-            columns: data[0].len(),
-            matrix: data,
-        };
-        new_matrix.check_row_lengths();
-        new_matrix.make_square();
+        let original_rows = data.len();
+        let original_columns = data.iter().map(|row| row.len()).max().unwrap_or(0);
+        let size = original_rows.max(original_columns);
+
+        let mut new_matrix = Self::new_empty(size, size);
+        new_matrix.original_rows = original_rows;
+        new_matrix.original_columns = original_columns;
+        for (row, values) in data.iter().enumerate() {
+            new_matrix[row][..values.len()].copy_from_slice(values);
+        }
         new_matrix
     }
 
-    fn make_square(&mut self) {
-        if self.rows == self.columns { return; }
-        if self.rows > self.columns {
-            let diff = self.rows - self.columns;
-            for _ in 0..diff {
-                self.add_column();
-                self.columns += 1;
-            }
-        } else if self.rows < self.columns {
-            let diff = self.columns - self.rows;
-            for _ in 0..diff {
-                self.add_row();
-                self.rows += 1;
-            }
+    /// Builds a matrix with the shape of `data` instead of padding it to a
+    /// square, short rows are filled with zeros. `MadarskaMetodaMunkres` and
+    /// `MadarskaMetodaPotentials` solve such matrices directly, `MadarskaMetoda`
+    /// and the reference solvers pad them to a square first.
+    pub fn new_rectangular(data: Vec<Vec<i32>>) -> Self {
+        let rows = data.len();
+        let columns = data.iter().map(|row| row.len()).max().unwrap_or(0);
+        let mut new_matrix = Self::new_empty(rows, columns);
+        for (row, values) in data.iter().enumerate() {
+            new_matrix[row][..values.len()].copy_from_slice(values);
+        }
+        new_matrix
+    }
+
+    /// Pads a rectangular matrix with zero rows or columns like `Matrix::new`,
+    /// the padded cells become dummy cells.
+    pub fn into_square(self) -> Self {
+        if self.rows == self.columns {
+            return self;
+        }
+        let size = self.rows.max(self.columns);
+        let mut new_matrix = Self::new_empty(size, size);
+        for (row, values) in self.iter_rows().enumerate() {
+            new_matrix[row][..values.len()].copy_from_slice(values);
         }
+        new_matrix.original_rows = self.original_rows;
+        new_matrix.original_columns = self.original_columns;
+        new_matrix.row_labels = self.row_labels;
+        new_matrix.column_labels = self.column_labels;
+        new_matrix
     }
 
-    fn add_column(&mut self) {
-        for row in 0..self.rows {
-            self.matrix[row].push(0);
+    /// Builds a matrix from row-major `data` without any padding.
+    pub fn from_flat(rows: usize, columns: usize, data: Vec<i32>) -> Self {
+        assert_eq!(rows * columns, data.len(), "Data length must be rows * columns");
+        Self {
+            rows,
+            columns,
+            data,
+            original_rows: rows,
+            original_columns: columns,
+            row_labels: None,
+            column_labels: None,
         }
     }
 
-    fn add_row(&mut self) {
-        self.matrix.push(vec![0; self.columns]);
+    pub fn rows(&self) -> usize {
+        self.rows
     }
 
-    fn check_row_lengths(&mut self) {
-        let mut max_row_len = self.matrix.len();
-        let mut min_row_len = self.matrix.len();
-        
-        for row in 0..self.rows {
-            if self.matrix[row].len() > max_row_len {
-                max_row_len = self.matrix[row].len();
-            }
-            if self.matrix[row].len() < min_row_len {
-                min_row_len = self.matrix[row].len();
-            }
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Rows of the input before padding, the rows past it are dummy rows.
+    pub fn original_rows(&self) -> usize {
+        self.original_rows
+    }
+
+    /// Columns of the input before padding, the columns past it are dummy columns.
+    pub fn original_columns(&self) -> usize {
+        self.original_columns
+    }
+
+    /// Turns the matrix into `rows` x `columns` zeros without dummy cells, reusing its buffer.
+    pub(crate) fn reset_zeroed(&mut self, rows: usize, columns: usize) {
+        self.rows = rows;
+        self.columns = columns;
+        self.original_rows = rows;
+        self.original_columns = columns;
+        self.data.clear();
+        self.data.resize(rows * columns, 0);
+    }
+
+    pub fn row(&self, index: usize) -> &[i32] {
+        &self.data[index * self.columns..(index + 1) * self.columns]
+    }
+
+    pub fn row_mut(&mut self, index: usize) -> &mut [i32] {
+        let columns = self.columns;
+        &mut self.data[index * columns..(index + 1) * columns]
+    }
+
+    /// Iterates over a column without allocating.
+    pub fn column(&self, index: usize) -> StepBy<Iter<'_, i32>> {
+        assert!(index < self.columns, "Column index out of range");
+        self.data[index..].iter().step_by(self.columns.max(1))
+    }
+
+    pub fn iter_rows(&self) -> Chunks<'_, i32> {
+        self.data.chunks(self.columns.max(1))
+    }
+
+    pub fn as_slice(&self) -> &[i32] {
+        &self.data
+    }
+
+    pub fn to_vec(&self) -> Vec<Vec<i32>> {
+        self.iter_rows().map(<[i32]>::to_vec).collect()
+    }
+
+    /// Copies a row or a column into a new vector, prefer `row` and `column` to avoid allocating.
+    pub fn get(&self, position: Position, index: usize) -> Vec<i32> {
+        match position {
+            Position::Row => self.row(index).to_vec(),
+            Position::Column => self.column(index).copied().collect(),
         }
+    }
 
-        if max_row_len != min_row_len {
-            self.columns = max_row_len;
-            for row in 0..self.rows {
-                if self.matrix[row].len() < max_row_len {
-                    for _ in 0..(max_row_len - self.matrix[row].len()) {
-                        self.matrix[row].push(0);
-                    }
-                }
-            }
+    /// Names the original rows and columns, padded dummy rows and columns stay unlabelled.
+    pub fn with_labels(mut self, row_labels: Vec<String>, column_labels: Vec<String>) -> Result<Self, String> {
+        if row_labels.len() != self.original_rows {
+            return Err("Every row needs exactly one label".to_owned());
         }
+        if column_labels.len() != self.original_columns {
+            return Err("Every column needs exactly one label".to_owned());
+        }
+        self.row_labels = Some(row_labels);
+        self.column_labels = Some(column_labels);
+        Ok(self)
     }
 
-    fn invert_matrix_values(&self) -> Self {
-        let mut m = Self {
-            rows: self.rows,
-            columns: self.columns,
-            matrix: self.matrix.clone()
-        };
+    pub fn row_label(&self, row: usize) -> Option<&str> {
+        self.row_labels.as_ref().and_then(|labels| labels.get(row)).map(String::as_str)
+    }
 
-        for row in 0..m.rows {
-            for col in 0..m.columns {
-                m.matrix[row][col] *= -1;
-            }
-        }
-        m
+    pub fn column_label(&self, col: usize) -> Option<&str> {
+        self.column_labels.as_ref().and_then(|labels| labels.get(col)).map(String::as_str)
     }
 
-    fn get(&self, position: Position, index: usize) -> Vec<i32> {
-        match position {
-            Position::Row => {
-                return self.matrix[index].clone();
-            },
-            Position::Column => {
-                let mut temp = Vec::new();
-                for i in 0..self.columns {
-                    temp.push(self.matrix[i][index]);
+    pub fn is_dummy(&self, row: usize, col: usize) -> bool {
+        row >= self.original_rows || col >= self.original_columns
+    }
+
+    fn assigned_cells(&self, assignment_mask: &Matrix) -> Vec<(usize, usize)> {
+        let mut cells = Vec::new();
+        for row in 0..assignment_mask.rows {
+            for col in 0..assignment_mask.columns {
+                if assignment_mask[row][col] == 1 && !self.is_dummy(row, col) {
+                    cells.push((row, col));
                 }
-                return temp;
-            },
+            }
         }
+        cells
+    }
+
+    /// Total cost of the assigned cells, summed in `i64` so only a total that
+    /// does not fit in `i32` is an overflow.
+    fn assignment_cost(&self, assignment_mask: &Matrix) -> Result<i32, SolveError> {
+        let total: i64 = self.assigned_cells(assignment_mask).iter().map(|&(row, col)| self[row][col] as i64).sum();
+        i32::try_from(total).map_err(|_| SolveError::Overflow)
+    }
+
+    fn assignments(&self, assignment_mask: &Matrix) -> Vec<Assignment> {
+        self.assigned_cells(assignment_mask)
+            .into_iter()
+            .map(|(row, column)| Assignment {
+                row,
+                column,
+                cost: self[row][column],
+                row_label: self.row_label(row).map(str::to_owned),
+                column_label: self.column_label(column).map(str::to_owned),
+            })
+            .collect()
     }
 
     fn find_min_row(&self, index: usize) -> i32 {
-        *self.matrix[index].iter().min().unwrap()
+        *self.row(index).iter().min().unwrap()
     }
 
     fn find_min_col(&self, index: usize) -> i32 {
-        *self.get(Position::Column, index).iter().min().unwrap()
+        *self.column(index).min().unwrap()
+    }
+}
+
+impl Clone for Matrix {
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows,
+            columns: self.columns,
+            data: self.data.clone(),
+            original_rows: self.original_rows,
+            original_columns: self.original_columns,
+            row_labels: self.row_labels.clone(),
+            column_labels: self.column_labels.clone(),
+        }
+    }
+
+    /// Copies `source` into the existing buffers instead of allocating new ones.
+    fn clone_from(&mut self, source: &Self) {
+        self.rows = source.rows;
+        self.columns = source.columns;
+        self.data.clone_from(&source.data);
+        self.original_rows = source.original_rows;
+        self.original_columns = source.original_columns;
+        self.row_labels.clone_from(&source.row_labels);
+        self.column_labels.clone_from(&source.column_labels);
     }
 }
 
+impl Index<usize> for Matrix {
+    type Output = [i32];
+
+    fn index(&self, row: usize) -> &[i32] {
+        self.row(row)
+    }
+}
+
+impl IndexMut<usize> for Matrix {
+    fn index_mut(&mut self, row: usize) -> &mut [i32] {
+        self.row_mut(row)
+    }
+}
+
+/// Phases of the `MadarskaMetoda` solver reported to a `MadarskaMetodaObserver`.
 #[derive(Debug, Clone)]
-struct Backup {
-    assignment_mask: Matrix,
-    assignment_count: usize,
-    crossed_rows: Vec<i32>,
-    crossed_columns: Vec<i32>,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MadarskaMetodaEvent {
+    Reduced { matrix: Matrix },
+    Assigned { row: usize, column: usize, arbitrary: bool },
+    AssignmentFinished { assignment_mask: Matrix, assignment_count: usize },
+    LinesDrawn { crossed_rows: Vec<i32>, crossed_columns: Vec<i32> },
+    /// The greedy assignment was extended along an augmenting path of zeros,
+    /// `path` holds the newly assigned cells.
+    Augmented { path: Vec<(usize, usize)> },
+    Adjusted { min: i32, matrix: Matrix },
+    Finished { result: i32 },
 }
 
-impl Backup {
-    fn new(madarska_metoda_obj: &MadarskaMetoda) -> Self {
-        Self {
-            assignment_mask: madarska_metoda_obj.assignment_mask.clone(),
-            assignment_count: madarska_metoda_obj.assignment_count.clone(),
-            crossed_rows: madarska_metoda_obj.crossed_rows.clone(),
-            crossed_columns: madarska_metoda_obj.crossed_columns.clone(),
-        }
+pub trait MadarskaMetodaObserver {
+    fn on_event(&mut self, event: &MadarskaMetodaEvent);
+}
+
+impl<F: FnMut(&MadarskaMetodaEvent)> MadarskaMetodaObserver for F {
+    fn on_event(&mut self, event: &MadarskaMetodaEvent) {
+        self(event)
     }
 }
 
@@ -153,102 +371,145 @@ pub struct MadarskaMetoda {
     assignment_count: usize,
     crossed_rows: Vec<i32>,
     crossed_columns: Vec<i32>,
-    backup: Option<Backup>,
-    possible_assignments: Vec<(usize, usize)>,
+    observer: Option<Box<dyn MadarskaMetodaObserver>>,
+    objective: Objective,
+    offset: i32,
+    stats: SolveStats,
 }
 
 impl MadarskaMetoda {
 
-    pub fn new(starting_matrix: &Matrix) -> Self {
+    /// Takes a `&Matrix`, a `MatrixView` or an owned matrix, a rectangular
+    /// one is padded to a square.
+    pub fn new<M: Into<Matrix>>(starting_matrix: M) -> Self {
+        let starting_matrix = starting_matrix.into().into_square();
         Self {
-            starting_matrix: starting_matrix.clone(),
             calculating_matrix: starting_matrix.clone(),
+            starting_matrix,
             assignment_mask: Matrix::new_empty(1, 1),
             assignment_count: 0,
             crossed_rows: Vec::new(),
             crossed_columns: Vec::new(),
-            backup: None,
-            possible_assignments: Vec::new(),
+            observer: None,
+            objective: Objective::Minimize,
+            offset: 0,
+            stats: SolveStats::default(),
         }
     }
 
-    pub fn solve(&mut self, maximize: Option<bool>) -> i32 {
-        let mut do_max = false;
-        if let Some(maximize) = maximize {
-            do_max = maximize;
+    pub fn set_observer(&mut self, observer: Box<dyn MadarskaMetodaObserver>) {
+        self.observer = Some(observer);
+    }
+
+    fn emit<F: FnOnce(&Self) -> MadarskaMetodaEvent>(&mut self, event: F) {
+        if let Some(mut observer) = self.observer.take() {
+            observer.on_event(&event(self));
+            self.observer = Some(observer);
         }
+    }
+
+    pub fn solve<O: Into<Objective>>(&mut self, objective: O) -> i32 {
+        self.solve_with_options(objective, &SolveOptions::default()).unwrap_or(-1)
+    }
 
-        if do_max {
-            self.calculating_matrix = self.calculating_matrix.invert_matrix_values();
+    fn limit_reached(&self, options: &SolveOptions, iterations: usize, reduced: bool) -> Result<(), SolveError> {
+        match options.check(iterations) {
+            Some(reason) => Err(SolveError::LimitReached {
+                reason,
+                partial: self.assignment(),
+                bound: reduction_bound(&self.starting_matrix, &self.calculating_matrix, self.objective, self.offset, reduced),
+            }),
+            None => Ok(()),
         }
+    }
 
-        self.first_step();
-        loop {
-            self.reset_assignment();
-            self.get_assignment();
-            
-            if self.assignment_count == self.starting_matrix.rows {
-                break;
-            }
+    /// Solves like `solve` but stops with `SolveError::LimitReached` once a limit
+    /// from `options` is hit. An iteration is one round of assignment, line
+    /// drawing and adjustment.
+    pub fn solve_with_options<O: Into<Objective>>(&mut self, objective: O, options: &SolveOptions) -> Result<i32, SolveError> {
+        let timer = Instant::now();
+        self.objective = objective.into();
+        if self.objective.is_maximize() {
+            self.offset = self.calculating_matrix.real_max();
+            self.calculating_matrix = self.calculating_matrix.max_offset()?;
+        }
 
-            self.second_step();
+        let result = self.run(options);
+        self.stats.elapsed += timer.elapsed();
+        result
+    }
 
+    pub fn stats(&self) -> &SolveStats {
+        &self.stats
+    }
 
-            let mut found_other_optimal_assignment = false;
-            if self.third_step().is_err() {
-                while self.assignment_count != self.starting_matrix.rows {
-                    if self.backup.is_some() {
-                        self.load_backup(self.backup.clone());
-                        if let Some((row, col)) = self.possible_assignments.pop() {
-                            self.make_assignment(row, col);
-                        } else {
-                            return -1;
-                        }
-                        self.get_assignment();
+    fn timed<T, F: FnOnce(&mut Self) -> T>(&mut self, phase: Phase, f: F) -> T {
+        let timer = Instant::now();
+        let output = f(self);
+        self.stats.record(phase, timer.elapsed());
+        output
+    }
 
-                    }
-                }
-                found_other_optimal_assignment = true;
-                // return -1;
-            }
+    fn run(&mut self, options: &SolveOptions) -> Result<i32, SolveError> {
+        self.stats.track_matrix(self.calculating_matrix.rows(), self.calculating_matrix.columns());
 
-            self.backup = None;
+        let mut iterations = 0;
+        self.limit_reached(options, iterations, false)?;
 
-            if found_other_optimal_assignment { break; }
-        }
+        self.timed(Phase::Reduction, Self::first_step)?;
+        self.stats.reductions += 1;
+        self.emit(|mm| MadarskaMetodaEvent::Reduced { matrix: mm.calculating_matrix.clone() });
+        loop {
+            self.limit_reached(options, iterations, true)?;
+            iterations += 1;
 
-        let mut result = 0;
-        for row in 0..self.starting_matrix.rows {
-            for col in 0..self.starting_matrix.columns {
-                if self.assignment_mask.matrix[row][col] == 0 { continue; }
-                result += self.starting_matrix.matrix[row][col];
+            self.reset_assignment();
+            self.timed(Phase::Assignment, |mm| {
+                mm.get_assignment();
+                mm.complete_assignment();
+            });
+            self.emit(|mm| MadarskaMetodaEvent::AssignmentFinished {
+                assignment_mask: mm.assignment_mask.clone(),
+                assignment_count: mm.assignment_count,
+            });
+            
+            if self.assignment_count == self.starting_matrix.rows() {
+                break;
             }
+
+            self.timed(Phase::Covering, Self::second_step);
+            self.stats.covering_iterations += 1;
+            self.emit(|mm| MadarskaMetodaEvent::LinesDrawn {
+                crossed_rows: mm.crossed_rows.clone(),
+                crossed_columns: mm.crossed_columns.clone(),
+            });
+
+            self.timed(Phase::Adjustment, Self::third_step)?;
         }
 
-        result
+        let result = self.starting_matrix.assignment_cost(&self.assignment_mask)?;
+        self.emit(|_| MadarskaMetodaEvent::Finished { result });
+        Ok(result)
     }
 
-    pub fn solve_timed(starting_matrix: &Matrix, maximize: Option<bool>) -> i32 {
-        let timer = Instant::now();
-        let res = MadarskaMetoda::new(starting_matrix).solve(maximize);
-        println!("{:?}s", timer.elapsed().as_micros() as f64 / 1000000 as f64 );
-        res
+    /// Assigned `(row, column)` pairs of the last solve, without padded dummy cells.
+    pub fn assignment(&self) -> Vec<(usize, usize)> {
+        self.starting_matrix.assigned_cells(&self.assignment_mask)
     }
-        
-    fn first_step(&mut self) {
-        for i in 0..self.calculating_matrix.rows {
-            let min = self.calculating_matrix.find_min_row(i);
-            for j in 0..self.calculating_matrix.rows {
-                self.calculating_matrix.matrix[i][j] -= min;
-            }
-        }
 
-        for i in 0..self.calculating_matrix.columns {
-            let min = self.calculating_matrix.find_min_col(i);
-            for j in 0..self.calculating_matrix.columns {
-                self.calculating_matrix.matrix[j][i] -= min;
-            }
-        }
+    pub fn labelled_assignment(&self) -> Vec<Assignment> {
+        self.starting_matrix.assignments(&self.assignment_mask)
+    }
+
+    pub fn solve_timed<O: Into<Objective>>(starting_matrix: &Matrix, objective: O) -> (i32, SolveStats) {
+        let mut mm = MadarskaMetoda::new(starting_matrix);
+        let res = mm.solve(objective);
+        (res, mm.stats)
+    }
+        
+    fn first_step(&mut self) -> Result<(), SolveError> {
+        self.calculating_matrix.reduce_rows()?;
+        self.calculating_matrix.reduce_columns()
     }
 
     fn get_assignment(&mut self) {
@@ -256,19 +517,20 @@ impl MadarskaMetoda {
         loop {
             let mut change_occured = false;
 
-            for row in 0..self.calculating_matrix.rows {
+            for row in 0..self.calculating_matrix.rows() {
                 let mut count = 0;
                 let mut last_col = 0;
                 if self.crossed_rows[row] == 1 { continue; }
-                for col in 0..self.calculating_matrix.columns {
+                for col in 0..self.calculating_matrix.columns() {
                     if self.crossed_columns[col] == 1 { continue; }
-                    if self.calculating_matrix.matrix[row][col] == 0 {
+                    if self.calculating_matrix[row][col] == 0 {
                         count += 1;
                         last_col = col;
                     }
                 }
                 if count == 1 {
                     self.make_assignment(row, last_col);
+                    self.emit(|_| MadarskaMetodaEvent::Assigned { row, column: last_col, arbitrary: false });
                     change_occured = true;
                     break;
                 }
@@ -276,19 +538,20 @@ impl MadarskaMetoda {
     
             if change_occured { continue; }
     
-            for col in 0..self.calculating_matrix.columns {
+            for col in 0..self.calculating_matrix.columns() {
                 let mut count = 0;
                 let mut last_row = 0;
                 if self.crossed_columns[col] == 1 { continue; }
-                for row in 0..self.calculating_matrix.rows {
+                for row in 0..self.calculating_matrix.rows() {
                     if self.crossed_rows[row] == 1 { continue; }
-                    if self.calculating_matrix.matrix[row][col] == 0 {
+                    if self.calculating_matrix[row][col] == 0 {
                         count += 1;
                         last_row = row;
                     }
                 }
                 if count == 1 {
                     self.make_assignment(last_row, col);
+                    self.emit(|_| MadarskaMetodaEvent::Assigned { row: last_row, column: col, arbitrary: false });
                     change_occured = true;
                     break;
                 }
@@ -298,18 +561,18 @@ impl MadarskaMetoda {
 
             ////////////////////////////////////////////////////////////////////
 
-            let mut arbitrary_selection_mask = Matrix::new_empty(self.starting_matrix.rows, self.starting_matrix.columns);
+            let mut arbitrary_selection_mask = Matrix::new_empty(self.starting_matrix.rows(), self.starting_matrix.columns());
 
-            for col in 0..self.calculating_matrix.columns {
+            for col in 0..self.calculating_matrix.columns() {
                 if self.crossed_columns[col] == 1 { continue; }
-                for row in 0..self.calculating_matrix.rows {
+                for row in 0..self.calculating_matrix.rows() {
                     if self.crossed_rows[row] == 1 { continue; }
-                    if self.calculating_matrix.matrix[row][col] == 0 {
-                        for r in (row + 1)..self.calculating_matrix.rows {
+                    if self.calculating_matrix[row][col] == 0 {
+                        for r in (row + 1)..self.calculating_matrix.rows() {
                             if self.crossed_rows[r] == 1 { continue; }
-                            if self.calculating_matrix.matrix[r][col] == 0 {
-                                arbitrary_selection_mask.matrix[row][col] += 1;                              
-                                arbitrary_selection_mask.matrix[r][col] += 1;
+                            if self.calculating_matrix[r][col] == 0 {
+                                arbitrary_selection_mask[row][col] += 1;                              
+                                arbitrary_selection_mask[r][col] += 1;
                             }
                         }
                         
@@ -321,20 +584,20 @@ impl MadarskaMetoda {
             let mut selection_row = None;
             let mut selection_col = None;
 
-            for row in 0..self.calculating_matrix.rows {
+            for row in 0..self.calculating_matrix.rows() {
                 if self.crossed_rows[row] == 1 { continue; }
-                for col in 0..self.calculating_matrix.columns {
+                for col in 0..self.calculating_matrix.columns() {
                     if self.crossed_columns[col] == 1 { continue; }
-                    if self.calculating_matrix.matrix[row][col] == 0 {
-                        for c in (col + 1)..self.calculating_matrix.columns {
+                    if self.calculating_matrix[row][col] == 0 {
+                        for c in (col + 1)..self.calculating_matrix.columns() {
                             if self.crossed_columns[c] == 1 { continue; }
-                            if self.calculating_matrix.matrix[row][c] == 0 {
-                                arbitrary_selection_mask.matrix[row][col] += 1;
-                                arbitrary_selection_mask.matrix[row][c] += 1;
+                            if self.calculating_matrix[row][c] == 0 {
+                                arbitrary_selection_mask[row][col] += 1;
+                                arbitrary_selection_mask[row][c] += 1;
                             }
                         }
-                        if min_selection == -1 || arbitrary_selection_mask.matrix[row][col] < min_selection {
-                            min_selection = arbitrary_selection_mask.matrix[row][col];
+                        if min_selection == -1 || arbitrary_selection_mask[row][col] < min_selection {
+                            min_selection = arbitrary_selection_mask[row][col];
                             selection_row = Some(row);
                             selection_col = Some(col);
                         }
@@ -342,9 +605,9 @@ impl MadarskaMetoda {
                 }
             }
            
-            if selection_row.is_some() && selection_col.is_some() {
-                self.create_backup(&arbitrary_selection_mask, selection_row.unwrap(), selection_col.unwrap());
-                self.make_assignment(selection_row.unwrap(), selection_col.unwrap());
+            if let (Some(selection_row), Some(selection_col)) = (selection_row, selection_col) {
+                self.make_assignment(selection_row, selection_col);
+                self.emit(|_| MadarskaMetodaEvent::Assigned { row: selection_row, column: selection_col, arbitrary: true });
                 change_occured = true;
             }
 
@@ -357,18 +620,18 @@ impl MadarskaMetoda {
     fn make_assignment(&mut self, row: usize, col: usize) {
         self.crossed_rows[row] = 1;
         self.crossed_columns[col] = 1;
-        self.assignment_mask.matrix[row][col] = 1;
+        self.assignment_mask[row][col] = 1;
         self.assignment_count += 1;
     }
 
     fn second_step(&mut self) {
 
-        self.crossed_rows = vec![1; self.calculating_matrix.rows];
-        self.crossed_columns = vec![0; self.calculating_matrix.columns];
+        self.crossed_rows = vec![1; self.calculating_matrix.rows()];
+        self.crossed_columns = vec![0; self.calculating_matrix.columns()];
 
-        for row in 0..self.assignment_mask.rows {
-            for col in 0..self.assignment_mask.columns {
-                if self.assignment_mask.matrix[row][col] == 1 { self.crossed_rows[row] = 0; break; }
+        for row in 0..self.assignment_mask.rows() {
+            for col in 0..self.assignment_mask.columns() {
+                if self.assignment_mask[row][col] == 1 { self.crossed_rows[row] = 0; break; }
             }
         }
 
@@ -376,11 +639,11 @@ impl MadarskaMetoda {
 
             let mut change_occured = false;
 
-            for row in 0..self.assignment_mask.rows {
+            for row in 0..self.assignment_mask.rows() {
                 if self.crossed_rows[row] == 1 {
-                    for col in 0..self.assignment_mask.columns {
+                    for col in 0..self.assignment_mask.columns() {
                         if self.crossed_columns[col] == 1 { continue; }
-                        if self.calculating_matrix.matrix[row][col] == 0 {
+                        if self.calculating_matrix[row][col] == 0 {
                             self.crossed_columns[col] = 1;
                             change_occured = true;
                         }
@@ -388,11 +651,11 @@ impl MadarskaMetoda {
                 }
             }
 
-            for col in 0..self.assignment_mask.columns {
+            for col in 0..self.assignment_mask.columns() {
                 if self.crossed_columns[col] == 1 {
-                    for row in 0..self.assignment_mask.rows {
+                    for row in 0..self.assignment_mask.rows() {
                         if self.crossed_rows[row] == 1 { continue; }
-                        if self.assignment_mask.matrix[row][col] == 1 {
+                        if self.assignment_mask[row][col] == 1 {
                             self.crossed_rows[row] = 1;
                             change_occured = true;
                         }
@@ -406,77 +669,77 @@ impl MadarskaMetoda {
         self.crossed_rows.iter_mut().for_each(|val| if *val == 0 { *val = 1} else { *val = 0});
     }
 
-    fn third_step(&mut self) -> Result<bool, String> {
+    fn third_step(&mut self) -> Result<(), SolveError> {
         let min = self.minimum();
-        let min = match min {
-            Some(m) => m,
-            None => return Err("Min is 0".to_owned()),
-        };
-        for row in 0..self.calculating_matrix.rows {
-            if self.crossed_rows[row] == 1 { continue; }
-            for col in 0..self.calculating_matrix.columns {
-                self.calculating_matrix.matrix[row][col] -= min;
+        self.calculating_matrix.adjust(min, &self.crossed_rows, &self.crossed_columns)?;
+        self.stats.reductions += 1;
+        self.emit(|mm| MadarskaMetodaEvent::Adjusted { min, matrix: mm.calculating_matrix.clone() });
+        Ok(())
+    }
+
+    /// With a maximum assignment there are fewer lines than rows, so some cell
+    /// is always left uncovered.
+    fn minimum(&mut self) -> i32 {
+        self.calculating_matrix.uncovered_min(&self.crossed_rows, &self.crossed_columns).unwrap()
+    }
+
+    /// Extends the greedy assignment to a maximum one on the zero cells with
+    /// augmenting paths, so an unlucky arbitrary choice in `get_assignment`
+    /// can never leave a complete assignment unfound.
+    fn complete_assignment(&mut self) {
+        let mut column_match = vec![None; self.calculating_matrix.columns()];
+        let mut unassigned_rows = Vec::new();
+        for (row, values) in self.assignment_mask.iter_rows().enumerate() {
+            match values.iter().position(|&v| v == 1) {
+                Some(col) => column_match[col] = Some(row),
+                None => unassigned_rows.push(row),
             }
         }
 
-        for col in 0..self.calculating_matrix.columns {
-            if self.crossed_columns[col] == 0 { continue; }
-            for row in 0..self.calculating_matrix.rows {
-                self.calculating_matrix.matrix[row][col] += min;
-            }
-        }
-        Ok(true)
-    }
+        for row in unassigned_rows {
+            let mut visited = vec![false; self.calculating_matrix.columns()];
+            let mut path = Vec::new();
+            if !self.augment(row, &mut column_match, &mut visited, &mut path) { continue; }
 
-    fn minimum(&mut self) -> Option<i32> {
-        let mut non_crossed = Vec::new();
-        for row in 0..self.crossed_rows.len() {
-            if self.crossed_rows[row] == 1 { continue; }
-            for col in 0..self.crossed_columns.len() {
-                if self.crossed_columns[col] == 1 { continue; }
-                non_crossed.push(self.calculating_matrix.matrix[row][col]);
+            for &(row, col) in &path {
+                self.assignment_mask[row].iter_mut().for_each(|v| *v = 0);
+                self.assignment_mask[row][col] = 1;
             }
+            self.assignment_count += 1;
+            self.stats.augmentations += 1;
+            self.emit(|_| MadarskaMetodaEvent::Augmented { path });
         }
-
-        non_crossed.into_iter().min()
     }
 
-    fn create_backup(&mut self, arbitrary_selection_mask: &Matrix, row: usize, col: usize) {
-        if self.backup.is_none() {
-            let mut possible_assignments = Vec::new();
-            let min_val = arbitrary_selection_mask.matrix[row][col];
-            for row in 0..arbitrary_selection_mask.rows {
-                if self.crossed_rows[row] == 1 { continue; }
-                for col in 0..arbitrary_selection_mask.columns {
-                    if self.crossed_columns[col] == 1 { continue; }
-                    if arbitrary_selection_mask.matrix[row][col] == min_val {
-                        possible_assignments.push((row, col));
-                    }
-                }
+    /// Looks for an alternating path of zeros from `row` to an unassigned
+    /// column, every visited column is tried at most once.
+    fn augment(&self, row: usize, column_match: &mut [Option<usize>], visited: &mut [bool], path: &mut Vec<(usize, usize)>) -> bool {
+        for col in 0..self.calculating_matrix.columns() {
+            if visited[col] || self.calculating_matrix[row][col] != 0 { continue; }
+            visited[col] = true;
+            let free = match column_match[col] {
+                Some(next) => self.augment(next, column_match, visited, path),
+                None => true,
+            };
+            if free {
+                column_match[col] = Some(row);
+                path.push((row, col));
+                return true;
             }
-            possible_assignments.reverse();
-            self.backup = Some(Backup::new(&self));
-            self.possible_assignments = possible_assignments;
         }
+        false
     }
 
     fn reset_assignment(&mut self) {
         self.assignment_count = 0;
-        self.assignment_mask = Matrix::new_empty(self.calculating_matrix.rows, self.calculating_matrix.columns);
-        self.crossed_rows = vec![0; self.calculating_matrix.rows];
-        self.crossed_columns = vec![0; self.calculating_matrix.columns];
-    }
-
-    fn load_backup(&mut self, backup: Option<Backup>) {
-        if let Some(backup) = backup {
-            self.assignment_count = backup.assignment_count;
-            self.assignment_mask = backup.assignment_mask;
-            self.crossed_rows = backup.crossed_rows;
-            self.crossed_columns = backup.crossed_columns;
-        }
+        self.assignment_mask = Matrix::new_empty(self.calculating_matrix.rows(), self.calculating_matrix.columns());
+        self.crossed_rows = vec![0; self.calculating_matrix.rows()];
+        self.crossed_columns = vec![0; self.calculating_matrix.columns()];
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 struct Path {
     path: Vec<[usize;2]>,
     path_count: usize,
@@ -485,9 +748,11 @@ struct Path {
 }
 
 impl Path {
-    fn new(row: usize) -> Self {
+    /// An augmenting path alternates primed and starred zeros, with fewer stars
+    /// than the shorter side of the matrix it never gets longer than twice that side.
+    fn new(rows: usize, columns: usize) -> Self {
         Self {
-            path: vec![[0;2];row * 2],
+            path: vec![[0;2];rows.min(columns) * 2],
             path_count: 0,
             starting_row: 0,
             starting_column: 0,
@@ -495,6 +760,103 @@ impl Path {
     }
 }
 
+/// State of the Munkres solver after a single step.
+/// In `assignment_mask` starred zeros are 1 and primed zeros are 2.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MunkresSnapshot {
+    pub step: usize,
+    pub next_step: usize,
+    pub calculating_matrix: Matrix,
+    pub assignment_mask: Matrix,
+    pub crossed_rows: Vec<usize>,
+    pub crossed_columns: Vec<usize>,
+    pub path: Vec<(usize, usize)>,
+}
+
+/// Yields a snapshot after every step, or the error that stopped the solve
+/// as its last item.
+pub struct MunkresSteps<'a> {
+    munkres: &'a mut MadarskaMetodaMunkres,
+    error: Option<SolveError>,
+    failed: bool,
+}
+
+impl<'a> Iterator for MunkresSteps<'a> {
+    type Item = Result<MunkresSnapshot, SolveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let item = match self.error.take() {
+            Some(error) => Some(Err(error)),
+            None => self.munkres.step_once(),
+        };
+        self.failed = matches!(item, Some(Err(_)));
+        item
+    }
+}
+
+/// Full internal state of a `MadarskaMetodaMunkres` solve taken at a step
+/// boundary, resuming from it gives the same result as an uninterrupted solve.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MunkresCheckpoint {
+    starting_matrix: Matrix,
+    calculating_matrix: Matrix,
+    assignment_mask: Matrix,
+    path: Path,
+    crossed_rows: Vec<usize>,
+    crossed_columns: Vec<usize>,
+    step: usize,
+    objective: Objective,
+    offset: i32,
+}
+
+impl MunkresCheckpoint {
+    /// Checks what the steps rely on: at most one star per line, some cell left
+    /// uncovered while looking for zeros or the minimum, and a path that starts
+    /// at a prime in a row without a star and ends within `path.path`.
+    fn check_state(&self) -> Result<(), String> {
+        let mask = &self.assignment_mask;
+        let (rows, columns) = (mask.rows(), mask.columns());
+        let stars_in_row = |row: usize| mask[row].iter().filter(|&&v| v == 1).count();
+        let stars_in_column = |col: usize| mask.column(col).filter(|&&v| v == 1).count();
+        if (0..rows).any(|row| stars_in_row(row) > 1) || (0..columns).any(|col| stars_in_column(col) > 1) {
+            return Err("Checkpoint has more than one star in a line".to_owned());
+        }
+
+        let crossed = self.crossed_rows.iter().chain(&self.crossed_columns).filter(|&&v| v == 1).count();
+        if (self.step == 4 || self.step == 6) && crossed >= rows.min(columns) {
+            return Err("Checkpoint covers every cell".to_owned());
+        }
+
+        if self.step == 5 {
+            let (row, mut col) = (self.path.starting_row, self.path.starting_column);
+            if mask[row][col] != 2 || stars_in_row(row) > 0 {
+                return Err("Checkpoint path does not start at a prime without a star in its row".to_owned());
+            }
+            let mut length = 1;
+            while let Some(star_row) = (0..rows).find(|&r| mask[r][col] == 1) {
+                col = match (0..columns).find(|&c| mask[star_row][c] == 2) {
+                    Some(prime_col) => prime_col,
+                    None => return Err("Checkpoint path ends at a star without a prime".to_owned()),
+                };
+                length += 2;
+                if length > self.path.path.len() {
+                    return Err("Checkpoint path is longer than the matrix allows".to_owned());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn step(&self) -> usize {
+        self.step
+    }
+}
+
 pub struct MadarskaMetodaMunkres {
     pub starting_matrix: Matrix,
     calculating_matrix: Matrix,
@@ -503,39 +865,48 @@ pub struct MadarskaMetodaMunkres {
     crossed_rows: Vec<usize>,
     crossed_columns: Vec<usize>,
     step: usize,
+    objective: Objective,
+    offset: i32,
+    stats: SolveStats,
 }
 
 impl MadarskaMetodaMunkres {
 
-    pub fn new(matrica: &Matrix) -> Self {
+    /// Takes a `&Matrix`, a `MatrixView` or an owned matrix.
+    pub fn new<M: Into<Matrix>>(matrica: M) -> Self {
+        let matrica = matrica.into();
         MadarskaMetodaMunkres {
-            starting_matrix: matrica.clone(),
+            assignment_mask: Matrix::new_empty(matrica.rows(), matrica.columns()),
+            path: Path::new(matrica.rows(), matrica.columns()),
+            crossed_rows: vec![0;matrica.rows()],
+            crossed_columns: vec![0;matrica.columns()],
             calculating_matrix: matrica.clone(),
-            assignment_mask: Matrix::new_empty(matrica.rows, matrica.columns),
-            path: Path::new(matrica.rows),
-            crossed_rows: vec![0;matrica.rows],
-            crossed_columns: vec![0;matrica.columns],
+            starting_matrix: matrica,
             step: 1,
+            objective: Objective::Minimize,
+            offset: 0,
+            stats: SolveStats::default(),
         }
     }
 
-    fn first_step(&mut self) {
-
-        for i in 0..self.calculating_matrix.rows {
-            let min = self.calculating_matrix.find_min_row(i);
-            for j in 0..self.calculating_matrix.rows {
-                self.calculating_matrix.matrix[i][j] -= min;
-            }
+    /// Reduces along the shorter side, so every line that has to be assigned
+    /// gets a zero. Reducing the longer side of a rectangular matrix would
+    /// charge lines that stay unassigned.
+    fn first_step(&mut self) -> Result<(), SolveError> {
+        if self.calculating_matrix.rows() > self.calculating_matrix.columns() {
+            self.calculating_matrix.reduce_columns()?;
+        } else {
+            self.calculating_matrix.reduce_rows()?;
         }
-
         self.step = 2;
+        Ok(())
     }
 
     fn second_step(&mut self) {
-        for row in 0..self.calculating_matrix.rows {
-            for col in 0..self.calculating_matrix.columns {
-                if self.calculating_matrix.matrix[row][col] == 0 && self.crossed_rows[row] == 0 && self.crossed_columns[col] == 0 {
-                    self.assignment_mask.matrix[row][col] = 1;
+        for row in 0..self.calculating_matrix.rows() {
+            for col in 0..self.calculating_matrix.columns() {
+                if self.calculating_matrix[row][col] == 0 && self.crossed_rows[row] == 0 && self.crossed_columns[col] == 0 {
+                    self.assignment_mask[row][col] = 1;
                     self.crossed_rows[row] = 1;
                     self.crossed_columns[col] = 1;
                 }
@@ -548,9 +919,9 @@ impl MadarskaMetodaMunkres {
     }
 
     fn third_step(&mut self) {
-        for row in 0..self.calculating_matrix.rows {
-            for col in 0..self.calculating_matrix.columns {
-                if self.assignment_mask.matrix[row][col] == 1 {
+        for row in 0..self.calculating_matrix.rows() {
+            for col in 0..self.calculating_matrix.columns() {
+                if self.assignment_mask[row][col] == 1 {
                     self.crossed_columns[col] = 1;
                 }
             }
@@ -558,13 +929,13 @@ impl MadarskaMetodaMunkres {
 
         let mut col_count = 0;
 
-        for col in 0..self.calculating_matrix.columns {
+        for col in 0..self.calculating_matrix.columns() {
             if self.crossed_columns[col] == 1 {
                 col_count += 1;
             }
         }
 
-        if col_count >= self.calculating_matrix.columns || col_count >= self.calculating_matrix.rows {
+        if col_count >= self.calculating_matrix.columns() || col_count >= self.calculating_matrix.rows() {
             self.step = 7;
         } else {
             self.step = 4;
@@ -572,11 +943,11 @@ impl MadarskaMetodaMunkres {
     }
 
     fn get_noncrossed_zero(&self) -> Option<(usize, usize)>{
-        for _row in 0..self.calculating_matrix.rows {
+        for _row in 0..self.calculating_matrix.rows() {
             if self.crossed_rows[_row] == 1 { continue; }
-            for _col in 0..self.calculating_matrix.columns {
+            for _col in 0..self.calculating_matrix.columns() {
                 if self.crossed_columns[_col] == 1 { continue; }
-                if self.calculating_matrix.matrix[_row][_col] == 0 {
+                if self.calculating_matrix[_row][_col] == 0 {
                     return Some((_row, _col));
                 }
             }
@@ -587,8 +958,8 @@ impl MadarskaMetodaMunkres {
 
     fn is_star_in_row(&mut self, row: usize) -> bool {
         let mut output = false;
-        for col in 0..self.calculating_matrix.columns {
-            if self.assignment_mask.matrix[row][col] == 1 {
+        for col in 0..self.calculating_matrix.columns() {
+            if self.assignment_mask[row][col] == 1 {
                 output = true;
             }
         }
@@ -596,24 +967,13 @@ impl MadarskaMetodaMunkres {
     }
 
     fn get_star_in_row(&mut self, row: usize) -> Option<usize> {
-        for col in 0..self.calculating_matrix.columns {
-            if self.assignment_mask.matrix[row][col] == 1 {
-                return Some(col);
-            }
-        }
-        None
+        (0..self.calculating_matrix.columns()).find(|&col| self.assignment_mask[row][col] == 1)
     }
 
     fn fourth_step(&mut self) {
         loop {
-            let opt = self.get_noncrossed_zero();
-
-            if opt.is_none() {
-                self.step = 6;
-                break;
-            } else {
-                let (row, mut column) = opt.unwrap();
-                self.assignment_mask.matrix[row][column] = 2;
+            if let Some((row, mut column)) = self.get_noncrossed_zero() {
+                self.assignment_mask[row][column] = 2;
                 if self.is_star_in_row(row) {
                     match self.get_star_in_row(row) {
                         Some(c) => column = c,
@@ -627,50 +987,41 @@ impl MadarskaMetodaMunkres {
                     self.path.starting_column = column;
                     break;
                 }
+            } else {
+                self.step = 6;
+                break;
             }
         }
     }
 
     fn get_star_row_index(&mut self, column: usize) -> Option<usize> {
-        for row in 0..self.calculating_matrix.rows {
-            if self.assignment_mask.matrix[row][column] == 1 {
-                return Some(row);
-            }
-        }
-        None
+        (0..self.calculating_matrix.rows()).find(|&row| self.assignment_mask[row][column] == 1)
     }
 
     fn get_prime_column_index(&mut self, row: usize) -> Option<usize> {
-        for col in 0..self.calculating_matrix.columns {
-            if self.assignment_mask.matrix[row][col] == 2 {
-                return Some(col);
-            }
-        }
-        None
+        (0..self.calculating_matrix.columns()).find(|&col| self.assignment_mask[row][col] == 2)
     }
 
     fn unstar_starred_star_primed(&mut self) {
         for p in 0..self.path.path_count {
-            if self.assignment_mask.matrix[self.path.path[p][0]][self.path.path[p][1]] == 1 {
-                self.assignment_mask.matrix[self.path.path[p][0]][self.path.path[p][1]] = 0;
+            if self.assignment_mask[self.path.path[p][0]][self.path.path[p][1]] == 1 {
+                self.assignment_mask[self.path.path[p][0]][self.path.path[p][1]] = 0;
             } else {
-                self.assignment_mask.matrix[self.path.path[p][0]][self.path.path[p][1]] = 1;
+                self.assignment_mask[self.path.path[p][0]][self.path.path[p][1]] = 1;
             }
         }
     }
 
     fn reset_crossed(&mut self) {
-        for i in 0..self.calculating_matrix.rows {
-            self.crossed_rows[i] = 0;
-            self.crossed_columns[i] = 0;
-        }
+        self.crossed_rows.iter_mut().for_each(|v| *v = 0);
+        self.crossed_columns.iter_mut().for_each(|v| *v = 0);
     }
 
     fn reset_prime(&mut self) {
-        for row in 0..self.calculating_matrix.rows {
-            for col in 0..self.calculating_matrix.columns {
-                if self.assignment_mask.matrix[row][col] == 2 {
-                    self.assignment_mask.matrix[row][col] = 0;
+        for row in 0..self.calculating_matrix.rows() {
+            for col in 0..self.calculating_matrix.columns() {
+                if self.assignment_mask[row][col] == 2 {
+                    self.assignment_mask[row][col] = 0;
                 }
             }
         }
@@ -705,72 +1056,224 @@ impl MadarskaMetodaMunkres {
     }
 
     fn get_min_value(&mut self) -> i32 {
-        let mut min = None;
-        for row in 0..self.calculating_matrix.rows {
-            for col in 0..self.calculating_matrix.columns {
-                if self.crossed_rows[row] == 0 && self.crossed_columns[col] == 0 {
-                    if min.is_none() {
-                        min = Some(self.calculating_matrix.matrix[row][col]);
-                    } else if min.unwrap() > self.calculating_matrix.matrix[row][col] {
-                        min = Some(self.calculating_matrix.matrix[row][col]);
-                    }
-                }
-            }
-        }
-        min.unwrap()
+        self.calculating_matrix.uncovered_min(&self.crossed_rows, &self.crossed_columns).unwrap()
     }
 
-    fn sixth_step(&mut self) {
+    fn sixth_step(&mut self) -> Result<(), SolveError> {
         let min = self.get_min_value();
-        for row in 0..self.calculating_matrix.rows {
-            for col in 0..self.calculating_matrix.columns {
-                if self.crossed_rows[row] == 1 {
-                    self.calculating_matrix.matrix[row][col] += min;
-                }
-                if self.crossed_columns[col] == 0 {
-                    self.calculating_matrix.matrix[row][col] -= min;
-                }
-            }
-            self.step = 4;
+        self.calculating_matrix.adjust(min, &self.crossed_rows, &self.crossed_columns)?;
+        self.step = 4;
+        Ok(())
+    }
+
+    fn get_result(&self) -> Result<i32, SolveError> {
+        self.starting_matrix.assignment_cost(&self.assignment_mask)
+    }
+
+    /// Assigned `(row, column)` pairs of the last solve, without padded dummy cells.
+    pub fn assignment(&self) -> Vec<(usize, usize)> {
+        self.starting_matrix.assigned_cells(&self.assignment_mask)
+    }
+
+    pub fn labelled_assignment(&self) -> Vec<Assignment> {
+        self.starting_matrix.assignments(&self.assignment_mask)
+    }
+
+    /// Applies the maximization transform before the first step, a solve that
+    /// has already started keeps its objective.
+    fn set_objective(&mut self, objective: Objective) -> Result<(), SolveError> {
+        if objective.is_maximize() && !self.objective.is_maximize() && self.step == 1 {
+            self.calculating_matrix = self.calculating_matrix.max_offset()?;
+            self.offset = self.starting_matrix.real_max();
+            self.objective = objective;
         }
+        Ok(())
     }
 
-    fn get_result(&mut self) -> i32 {
-        let mut result = 0;
-        for row in 0..self.calculating_matrix.rows {
-            for col in 0..self.calculating_matrix.columns {
-                if self.assignment_mask.matrix[row][col] == 1 {
-                    result += self.starting_matrix.matrix[row][col];
+    /// Runs the current step. A step that would overflow fails before changing
+    /// any state, so the solver stays on that step.
+    fn run_step(&mut self) -> Result<(), SolveError> {
+        let timer = Instant::now();
+        let step = self.step;
+        match step {
+            1 => self.first_step()?,
+            2 => self.second_step(),
+            3 => self.third_step(),
+            4 => self.fourth_step(),
+            5 => self.fifth_step(),
+            6 => self.sixth_step()?,
+            _ => panic!("Invalid step"),
+        }
+        let elapsed = timer.elapsed();
+
+        self.stats.elapsed += elapsed;
+        self.stats.track_matrix(self.calculating_matrix.rows(), self.calculating_matrix.columns());
+        match step {
+            1 | 6 => {
+                self.stats.record(if step == 1 { Phase::Reduction } else { Phase::Adjustment }, elapsed);
+                self.stats.reductions += 1;
+            },
+            3 | 4 => {
+                self.stats.record(Phase::Covering, elapsed);
+                self.stats.covering_iterations += 1;
+            },
+            _ => {
+                self.stats.record(Phase::Assignment, elapsed);
+                if step == 5 {
+                    self.stats.augmentations += 1;
                 }
+            },
+        }
+        Ok(())
+    }
+
+    pub fn stats(&self) -> &SolveStats {
+        &self.stats
+    }
+
+    pub fn solve_timed<O: Into<Objective>>(starting_matrix: &Matrix, objective: O) -> (i32, SolveStats) {
+        let mut mm = MadarskaMetodaMunkres::new(starting_matrix);
+        let res = mm.solve(objective);
+        (res, mm.stats)
+    }
+
+    fn snapshot(&self, step: usize) -> MunkresSnapshot {
+        let mut path = Vec::new();
+        if step == 5 {
+            for p in 0..self.path.path_count {
+                path.push((self.path.path[p][0], self.path.path[p][1]));
             }
         }
-        result 
+
+        MunkresSnapshot {
+            step,
+            next_step: self.step,
+            calculating_matrix: self.calculating_matrix.clone(),
+            assignment_mask: self.assignment_mask.clone(),
+            crossed_rows: self.crossed_rows.clone(),
+            crossed_columns: self.crossed_columns.clone(),
+            path,
+        }
     }
 
-    pub fn solve(&mut self, maximize: Option<bool>) -> i32 {
-        let mut do_max = false;
-        if let Some(maximize) = maximize {
-            do_max = maximize;
+    /// Executes the current step and returns the resulting state, `None` once
+    /// solved. A step that overflows returns the error and is not executed.
+    pub fn step_once(&mut self) -> Option<Result<MunkresSnapshot, SolveError>> {
+        if self.step == 7 {
+            return None;
         }
+        let step = self.step;
+        Some(self.run_step().map(|_| self.snapshot(step)))
+    }
 
-        if do_max {
-            self.calculating_matrix = self.calculating_matrix.invert_matrix_values();
+    /// Iterates over the remaining steps, yielding a snapshot after each one.
+    /// Ends with an error when the values overflow the maximization transform
+    /// or a step.
+    pub fn steps<O: Into<Objective>>(&mut self, objective: O) -> MunkresSteps<'_> {
+        let error = self.set_objective(objective.into()).err();
+        MunkresSteps { munkres: self, error, failed: false }
+    }
+
+    pub fn result(&self) -> Option<i32> {
+        if self.step == 7 {
+            self.get_result().ok()
+        } else {
+            None
         }
+    }
 
-        loop {
-            match self.step {
-                1 => self.first_step(),
-                2 => self.second_step(),
-                3 => self.third_step(),
-                4 => self.fourth_step(),
-                5 => self.fifth_step(),
-                6 => self.sixth_step(),
-                7 => return self.get_result(),
-                _ => panic!("Invalid step"),
+    pub fn solve<O: Into<Objective>>(&mut self, objective: O) -> i32 {
+        self.solve_with_options(objective, &SolveOptions::default()).unwrap_or(-1)
+    }
+
+    /// Solves like `solve` but stops with `SolveError::LimitReached` once a limit
+    /// from `options` is hit, every executed step counts as one iteration.
+    pub fn solve_with_options<O: Into<Objective>>(&mut self, objective: O, options: &SolveOptions) -> Result<i32, SolveError> {
+        self.set_objective(objective.into())?;
+
+        let mut iterations = 0;
+        while self.step != 7 {
+            if let Some(reason) = options.check(iterations) {
+                return Err(SolveError::LimitReached {
+                    reason,
+                    partial: self.assignment(),
+                    bound: reduction_bound(&self.starting_matrix, &self.calculating_matrix, self.objective, self.offset, self.step > 1),
+                });
             }
+            self.run_step()?;
+            iterations += 1;
         }
+        self.get_result()
     }
 
+    /// Runs at most `max_steps` steps, returns the result if the solve finished.
+    pub fn solve_partial<O: Into<Objective>>(&mut self, objective: O, max_steps: usize) -> Option<i32> {
+        self.set_objective(objective.into()).ok()?;
+
+        for _ in 0..max_steps {
+            if self.step == 7 { break; }
+            self.run_step().ok()?;
+        }
+        self.result()
+    }
+
+    pub fn checkpoint(&self) -> MunkresCheckpoint {
+        MunkresCheckpoint {
+            starting_matrix: self.starting_matrix.clone(),
+            calculating_matrix: self.calculating_matrix.clone(),
+            assignment_mask: self.assignment_mask.clone(),
+            path: self.path.clone(),
+            crossed_rows: self.crossed_rows.clone(),
+            crossed_columns: self.crossed_columns.clone(),
+            step: self.step,
+            objective: self.objective,
+            offset: self.offset,
+        }
+    }
+
+    pub fn resume(checkpoint: MunkresCheckpoint) -> Result<Self, String> {
+        let rows = checkpoint.starting_matrix.rows();
+        let columns = checkpoint.starting_matrix.columns();
+        let matrices = [&checkpoint.starting_matrix, &checkpoint.calculating_matrix, &checkpoint.assignment_mask];
+        if matrices.iter().any(|m| m.rows() != rows || m.columns() != columns || m.data.len() != rows * columns) {
+            return Err("Checkpoint matrices have inconsistent dimensions".to_owned());
+        }
+        if checkpoint.crossed_rows.len() != rows || checkpoint.crossed_columns.len() != columns {
+            return Err("Checkpoint crossed lines do not match the matrix".to_owned());
+        }
+        let path_len = rows.min(columns) * 2;
+        if checkpoint.path.path.len() != path_len || checkpoint.path.path_count > path_len {
+            return Err("Checkpoint path does not match the matrix".to_owned());
+        }
+        let path = &checkpoint.path;
+        let starts_outside = path_len > 0 && (path.starting_row >= rows || path.starting_column >= columns);
+        if starts_outside || path.path.iter().any(|&[row, col]| row >= rows || col >= columns) {
+            return Err("Checkpoint path leaves the matrix".to_owned());
+        }
+        if checkpoint.assignment_mask.data.iter().any(|&mask| !(0..=2).contains(&mask)) {
+            return Err("Checkpoint mask values must be 0, 1 or 2".to_owned());
+        }
+        if checkpoint.crossed_rows.iter().chain(&checkpoint.crossed_columns).any(|&crossed| crossed > 1) {
+            return Err("Checkpoint crossed lines must be 0 or 1".to_owned());
+        }
+        if !(1..=7).contains(&checkpoint.step) {
+            return Err(format!("Invalid checkpoint step {}", checkpoint.step));
+        }
+        checkpoint.check_state()?;
+
+        Ok(Self {
+            starting_matrix: checkpoint.starting_matrix,
+            calculating_matrix: checkpoint.calculating_matrix,
+            assignment_mask: checkpoint.assignment_mask,
+            path: checkpoint.path,
+            crossed_rows: checkpoint.crossed_rows,
+            crossed_columns: checkpoint.crossed_columns,
+            step: checkpoint.step,
+            objective: checkpoint.objective,
+            offset: checkpoint.offset,
+            stats: SolveStats::default(),
+        })
+    }
 }
 
 /**************************************************/
@@ -780,6 +1283,7 @@ impl MadarskaMetodaMunkres {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     const RED: usize = 5;
     const STUPAC: usize = 5;
@@ -787,30 +1291,26 @@ mod tests {
     #[test]
     fn new_empty_matrix() {
         let m = Matrix::new_empty(RED, STUPAC);
-        assert_eq!(vec![vec![0;STUPAC];RED], m.matrix);
-        assert_eq!(RED, m.rows);
-        assert_eq!(STUPAC, m.columns);
+        assert_eq!(vec![vec![0;STUPAC];RED], m.to_vec());
+        assert_eq!(RED, m.rows());
+        assert_eq!(STUPAC, m.columns());
     }
     #[test]
     fn new_matrix() {
         let m = Matrix::new(vec![vec![0;STUPAC];RED]);
-        assert_eq!(vec![vec![0;STUPAC];RED], m.matrix);
-        assert_eq!(RED, m.rows);
-        assert_eq!(STUPAC, m.columns);
+        assert_eq!(vec![vec![0;STUPAC];RED], m.to_vec());
+        assert_eq!(RED, m.rows());
+        assert_eq!(STUPAC, m.columns());
     }
 
     #[test]
     fn min_row() {
-        let matrica = Matrix {
-            rows: 4,
-            columns: 4,
-            matrix: vec![
-                vec![10, 8,  4, 5],
-                vec![ 6, 2, 12, 3],
-                vec![ 3, 5,  6, 9],
-                vec![ 4, 7,  8, 6],
-            ]
-        };
+        let matrica = Matrix::new(vec![
+            vec![10, 8,  4, 5],
+            vec![ 6, 2, 12, 3],
+            vec![ 3, 5,  6, 9],
+            vec![ 4, 7,  8, 6],
+        ]);
         assert_eq!(4, matrica.find_min_row(0));
         assert_eq!(2, matrica.find_min_row(1));
         assert_eq!(3, matrica.find_min_row(2));
@@ -819,16 +1319,12 @@ mod tests {
 
     #[test]
     fn min_col() {
-        let matrica = Matrix {
-            rows: 4,
-            columns: 4,
-            matrix: vec![
-                vec![10, 8,  4, 5],
-                vec![ 6, 2, 12, 3],
-                vec![ 3, 5,  6, 9],
-                vec![ 4, 7,  8, 6],
-            ]
-        };
+        let matrica = Matrix::new(vec![
+            vec![10, 8,  4, 5],
+            vec![ 6, 2, 12, 3],
+            vec![ 3, 5,  6, 9],
+            vec![ 4, 7,  8, 6],
+        ]);
         assert_eq!(3, matrica.find_min_col(0));
         assert_eq!(2, matrica.find_min_col(1));
         assert_eq!(4, matrica.find_min_col(2));
@@ -837,47 +1333,56 @@ mod tests {
 
     #[test]
     fn get() {
-        let matrica = Matrix {
-            rows: 4,
-            columns: 4,
-            matrix: vec![
-                vec![10, 8,  4, 5],
-                vec![ 6, 2, 12, 3],
-                vec![ 3, 5,  6, 9],
-                vec![ 4, 7,  8, 6],
-            ]
-        };
+        let matrica = Matrix::new(vec![
+            vec![10, 8,  4, 5],
+            vec![ 6, 2, 12, 3],
+            vec![ 3, 5,  6, 9],
+            vec![ 4, 7,  8, 6],
+        ]);
         assert_eq!(vec![10, 8, 4, 5], matrica.get(Position::Row, 0));
         assert_eq!(vec![ 3, 5, 6, 9], matrica.get(Position::Row, 2));
         assert_eq!(vec![10, 6, 3, 4], matrica.get(Position::Column, 0));
         assert_eq!(vec![ 5, 3, 9, 6], matrica.get(Position::Column, 3));
     }
 
+    #[test]
+    fn flat_views() {
+        let matrica = Matrix::from_flat(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(&[4, 5, 6], matrica.row(1));
+        assert_eq!(vec![3, 6], matrica.column(2).copied().collect::<Vec<i32>>());
+        assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6]], matrica.to_vec());
+        assert_eq!(5, matrica[1][1]);
+        assert_eq!(&[1, 2, 3, 4, 5, 6], matrica.as_slice());
+
+        let mut matrica = Matrix::new(vec![vec![1, 2], vec![3]]);
+        matrica[1][1] = 7;
+        assert_eq!(vec![vec![1, 2], vec![3, 7]], matrica.to_vec());
+    }
+
+    #[test]
+    #[should_panic(expected = "Column index out of range")]
+    fn column_out_of_range() {
+        Matrix::new(vec![vec![1, 2], vec![3, 4]]).column(2).count();
+    }
+
     #[test]
     fn first_step_test() {
-        let matrica = Matrix {
-            rows: 4,
-            columns: 4,
-            matrix: vec![
-                vec![10, 8,  4, 5],
-                vec![ 6, 2, 12, 3],
-                vec![ 3, 5,  6, 9],
-                vec![ 4, 7,  8, 6],
-            ]
-        };
-        let after = Matrix {
-            rows: 4,
-            columns: 4,
-            matrix: vec![
-                vec![6, 4,  0, 0],
-                vec![4, 0, 10, 0],
-                vec![0, 2,  3, 5],
-                vec![0, 3,  4, 1],
-            ]
-        };
+        let matrica = Matrix::new(vec![
+            vec![10, 8,  4, 5],
+            vec![ 6, 2, 12, 3],
+            vec![ 3, 5,  6, 9],
+            vec![ 4, 7,  8, 6],
+        ]);
+        let after = Matrix::new(vec![
+            vec![6, 4,  0, 0],
+            vec![4, 0, 10, 0],
+            vec![0, 2,  3, 5],
+            vec![0, 3,  4, 1],
+        ]);
         let mut mm = MadarskaMetoda::new(&matrica);
-        mm.first_step();
-        assert_eq!(after.matrix, mm.calculating_matrix.matrix);
+        mm.first_step().unwrap();
+        assert_eq!(after.to_vec(), mm.calculating_matrix.to_vec());
     }
 
     #[test]
@@ -897,10 +1402,47 @@ mod tests {
         ]);
 
         let mut mm = MadarskaMetoda::new(&matrica);
-        mm.first_step();
+        mm.first_step().unwrap();
         mm.reset_assignment();
         mm.get_assignment();
-        assert_eq!(test_assignment.matrix, mm.assignment_mask.matrix);
+        assert_eq!(test_assignment.to_vec(), mm.assignment_mask.to_vec());
+    }
+
+    #[test]
+    fn observer_test() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let matrica = Matrix::new(vec![
+            vec![10, 8,  4, 5],
+            vec![ 6, 2, 12, 3],
+            vec![ 3, 5,  6, 9],
+            vec![ 4, 7,  8, 6],
+        ]);
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let recorder = Rc::clone(&events);
+
+        let mut mm = MadarskaMetoda::new(&matrica);
+        mm.set_observer(Box::new(move |event: &MadarskaMetodaEvent| recorder.borrow_mut().push(event.clone())));
+        let result = mm.solve(None);
+
+        let events = events.borrow();
+        match events.first() {
+            Some(MadarskaMetodaEvent::Reduced { matrix }) => assert_eq!(vec![
+                vec![6, 4,  0, 0],
+                vec![4, 0, 10, 0],
+                vec![0, 2,  3, 5],
+                vec![0, 3,  4, 1],
+            ], matrix.to_vec()),
+            other => panic!("Expected reduction first, got {:?}", other),
+        }
+        let assigned = events.iter().filter(|e| matches!(e, MadarskaMetodaEvent::Assigned { .. })).count();
+        assert!(assigned >= matrica.rows());
+        match events.last() {
+            Some(MadarskaMetodaEvent::Finished { result: r }) => assert_eq!(result, *r),
+            other => panic!("Expected finish last, got {:?}", other),
+        }
     }
 
     #[test]
@@ -925,68 +1467,83 @@ mod tests {
 
     #[test]
     fn third_step_test() {
-        let matrica = Matrix {
-            rows: 4,
-            columns: 4,
-            matrix: vec![
-                vec![6, 4,  0, 0],
-                vec![4, 0, 10, 0],
-                vec![0, 2,  3, 5],
-                vec![0, 3,  4, 1],
-            ]
-        };
-        let after = Matrix {
-            rows: 4,
-            columns: 4,
-            matrix: vec![
-                vec![7, 4,  0, 0],
-                vec![5, 0, 10, 0],
-                vec![0, 1,  2, 4],
-                vec![0, 2,  3, 0],
-            ]
-        };
+        let matrica = Matrix::new(vec![
+            vec![6, 4,  0, 0],
+            vec![4, 0, 10, 0],
+            vec![0, 2,  3, 5],
+            vec![0, 3,  4, 1],
+        ]);
+        let after = Matrix::new(vec![
+            vec![7, 4,  0, 0],
+            vec![5, 0, 10, 0],
+            vec![0, 1,  2, 4],
+            vec![0, 2,  3, 0],
+        ]);
         let mut mm = MadarskaMetoda::new(&matrica);
         mm.reset_assignment();
         mm.get_assignment();
         mm.second_step();
-        let _ = mm.third_step();
-        assert_eq!(after.matrix, mm.calculating_matrix.matrix);
+        mm.third_step().unwrap();
+        assert_eq!(after.to_vec(), mm.calculating_matrix.to_vec());
+    }
+
+    #[test]
+    fn complete_assignment_test() {
+        // Already reduced, the greedy pass assigns only four of the five rows.
+        let matrica = Matrix::new(vec![
+            vec![0, 0, 1, 1, 1],
+            vec![1, 0, 0, 0, 1],
+            vec![0, 1, 1, 1, 0],
+            vec![0, 1, 1, 1, 0],
+            vec![1, 0, 0, 0, 0],
+        ]);
+
+        let mut mm = MadarskaMetoda::new(&matrica);
+        mm.reset_assignment();
+        mm.get_assignment();
+        assert_eq!(4, mm.assignment_count);
+
+        let mut mm = MadarskaMetoda::new(&matrica);
+        assert_eq!(0, mm.solve(None));
+        assert!(reference::is_valid_assignment(&matrica, &mm.assignment()));
+        assert_eq!(1, mm.stats().augmentations);
+        assert_eq!(0, mm.stats().covering_iterations);
+
+        let matrica = Matrix::new(vec![
+            vec![0, 0, 1],
+            vec![0, 1, 1],
+            vec![1, 0, 0],
+        ]);
+        let mut mm = MadarskaMetoda::new(&matrica);
+        mm.reset_assignment();
+        mm.make_assignment(0, 0);
+        mm.complete_assignment();
+        assert_eq!(vec![vec![0, 1, 0], vec![1, 0, 0], vec![0, 0, 1]], mm.assignment_mask.to_vec());
+        assert_eq!(3, mm.assignment_count);
     }
     
     #[test]
     fn solve_test() {
-        let matrica = Matrix {
-            rows: 4,
-            columns: 4,
-            matrix: vec![
-                vec![10, 8,  4, 5],
-                vec![ 6, 2, 12, 3],
-                vec![ 3, 5,  6, 9],
-                vec![ 4, 7,  8, 6],
-            ]
-        };
+        let matrica = Matrix::new(vec![
+            vec![10, 8,  4, 5],
+            vec![ 6, 2, 12, 3],
+            vec![ 3, 5,  6, 9],
+            vec![ 4, 7,  8, 6],
+        ]);
 
-        let matrica2 = Matrix {
-            rows: 4,
-            columns: 4,
-            matrix: vec![
-                vec![1, 5, 7, 1],
-                vec![3, 1, 1, 7],
-                vec![2, 1, 2, 4],
-                vec![1, 3, 1, 3],
-            ]
-        };
+        let matrica2 = Matrix::new(vec![
+            vec![1, 5, 7, 1],
+            vec![3, 1, 1, 7],
+            vec![2, 1, 2, 4],
+            vec![1, 3, 1, 3],
+        ]);
 
-        let matrica3 = Matrix {
-            rows: 4,
-            columns: 4,
-            matrix: vec![
-                vec![1, 5, 7, 1],
-                vec![3, 1, 1, 7],
-                vec![2, 1, 4, 1],
-                vec![1, 3, 1, 3],
-            ]
-        };
+        let matrica3 = Matrix::new(vec![
+            vec![1, 5, 7, 1],
+            vec![3, 1, 1, 7],
+            vec![2, 1, 4, 1],
+            vec![1, 3, 1, 3],
+        ]);
 
         let matrica4 = Matrix::new(vec![
             vec![60, 59, 71, 15, 82],
@@ -1190,6 +1747,39 @@ mod tests {
         assert_eq!(1, mm.solve(None));
         let mut mm = MadarskaMetodaMunkres::new(&matrica16);
         assert_eq!(2, mm.solve(None));
+
+        let mut mm = MadarskaMetodaPotentials::new(&matrica);
+        assert_eq!(15, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica2);
+        assert_eq!(4, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica3);
+        assert_eq!(4, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica4);
+        assert_eq!(129, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica5);
+        assert_eq!(138, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica6);
+        assert_eq!(155, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica7);
+        assert_eq!(5, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica8);
+        assert_eq!(459, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica9);
+        assert_eq!(13, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica10);
+        assert_eq!(2848, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica11);
+        assert_eq!(50, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica12);
+        assert_eq!(0, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica13);
+        assert_eq!(20, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica14);
+        assert_eq!(7, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica15);
+        assert_eq!(1, mm.solve(None));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica16);
+        assert_eq!(2, mm.solve(None));
     }
 
     #[test]
@@ -1303,6 +1893,9 @@ mod tests {
 
         let mut mm = MadarskaMetodaMunkres::new(&matrica);
         assert_eq!(236, mm.solve(Some(false)));
+
+        let mut mm = MadarskaMetodaPotentials::new(&matrica);
+        assert_eq!(236, mm.solve(Some(false)));
     }
 
     #[test]
@@ -1316,8 +1909,8 @@ mod tests {
             vec![2],
         ]);
 
-        assert_eq!(vec![vec![1, 2], vec![0, 0]], matrica.matrix);
-        assert_eq!(vec![vec![1, 0], vec![2, 0]], matrica2.matrix);
+        assert_eq!(vec![vec![1, 2], vec![0, 0]], matrica.to_vec());
+        assert_eq!(vec![vec![1, 0], vec![2, 0]], matrica2.to_vec());
     }
 
     #[test]
@@ -1334,20 +1927,20 @@ mod tests {
         ]);
 
 
-        assert_eq!(vec![vec![1, 0], vec![1, 2]], matrica.matrix);
-        assert_eq!(vec![vec![1, 2, 0], vec![1, 0, 0], vec![0, 0, 0]], matrica2.matrix);
+        assert_eq!(vec![vec![1, 0], vec![1, 2]], matrica.to_vec());
+        assert_eq!(vec![vec![1, 2, 0], vec![1, 0, 0], vec![0, 0, 0]], matrica2.to_vec());
     }
 
     #[test]
-    fn invert_matrix_values_text() {
+    fn max_offset_text() {
         let matrica = Matrix::new(vec![
             vec![1, 2],
             vec![2, 4],
         ]);
 
-        let matrica = matrica.invert_matrix_values();
+        let matrica = matrica.max_offset().unwrap();
 
-        assert_eq!(vec![vec![-1, -2], vec![-2, -4]], matrica.matrix);
+        assert_eq!(vec![vec![3, 2], vec![2, 0]], matrica.to_vec());
     }
 
     #[test]
@@ -1361,6 +1954,8 @@ mod tests {
         assert_eq!(5, mm.solve(Some(true)));
         let mut mm = MadarskaMetodaMunkres::new(&matrica);
         assert_eq!(5, mm.solve(Some(true)));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica);
+        assert_eq!(5, mm.solve(Some(true)));
     }
     
     #[test]
@@ -1436,6 +2031,15 @@ mod tests {
         assert_eq!(11, mm.solve(Some(false)));
         let mut mm = MadarskaMetodaMunkres::new(&matrica2);
         assert_eq!(30, mm.solve(Some(true)));
+
+        let mut mm = MadarskaMetodaPotentials::new(&matrica);
+        assert_eq!(1, mm.solve(Some(false)));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica);
+        assert_eq!(341, mm.solve(Some(true)));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica2);
+        assert_eq!(11, mm.solve(Some(false)));
+        let mut mm = MadarskaMetodaPotentials::new(&matrica2);
+        assert_eq!(30, mm.solve(Some(true)));
     }
 
     #[test]
@@ -1447,7 +2051,7 @@ mod tests {
         ]);
 
         let mut mm = MadarskaMetodaMunkres::new(&matrica);
-        mm.first_step();
+        mm.first_step().unwrap();
 
         let expected_result = vec![
             vec![0, 1, 2],
@@ -1455,7 +2059,7 @@ mod tests {
             vec![0, 3, 6],
         ];
 
-        assert_eq!(expected_result, mm.calculating_matrix.matrix);
+        assert_eq!(expected_result, mm.calculating_matrix.to_vec());
     }
 
     #[test]
@@ -1475,7 +2079,7 @@ mod tests {
             vec![0, 0, 0],
         ];
 
-        assert_eq!(expected_result, mm.assignment_mask.matrix);
+        assert_eq!(expected_result, mm.assignment_mask.to_vec());
     }
 
     #[test]
@@ -1504,11 +2108,11 @@ mod tests {
         ]);
 
         let mut mm = MadarskaMetodaMunkres::new(&matrica);
-        mm.assignment_mask.matrix[0][0] = 1;
+        mm.assignment_mask[0][0] = 1;
 
-        assert_eq!(true, mm.is_star_in_row(0));
-        assert_eq!(false, mm.is_star_in_row(1));
-        assert_eq!(false, mm.is_star_in_row(2));
+        assert!(mm.is_star_in_row(0));
+        assert!(!mm.is_star_in_row(1));
+        assert!(!mm.is_star_in_row(2));
     }
 
     #[test]
@@ -1520,7 +2124,7 @@ mod tests {
         ]);
 
         let mut mm = MadarskaMetodaMunkres::new(&matrica);
-        mm.assignment_mask.matrix[0][0] = 1;
+        mm.assignment_mask[0][0] = 1;
         
         let star = mm.get_star_in_row(0).unwrap();
         assert_eq!(0, star);
@@ -1535,7 +2139,7 @@ mod tests {
         ]);
 
         let mut mm = MadarskaMetodaMunkres::new(&matrica);
-        mm.assignment_mask.matrix[0][0] = 1;
+        mm.assignment_mask[0][0] = 1;
         mm.crossed_columns[0] = 1;
         mm.fourth_step();
 
@@ -1548,7 +2152,7 @@ mod tests {
         let expected_crossed_rows = vec![1, 0, 0];
         let expected_crossed_columns = vec![0, 0, 0];
 
-        assert_eq!(expected_assignment_mask, mm.assignment_mask.matrix);
+        assert_eq!(expected_assignment_mask, mm.assignment_mask.to_vec());
         assert_eq!(expected_crossed_rows, mm.crossed_rows);
         assert_eq!(expected_crossed_columns, mm.crossed_columns);
     }
@@ -1562,7 +2166,7 @@ mod tests {
         ]);
 
         let mut mm = MadarskaMetodaMunkres::new(&matrica);
-        mm.assignment_mask.matrix[0][0] = 1;
+        mm.assignment_mask[0][0] = 1;
 
         assert_eq!(0, mm.get_star_row_index(0).unwrap());
         assert_eq!(None, mm.get_star_row_index(1));
@@ -1578,9 +2182,9 @@ mod tests {
         ]);
 
         let mut mm = MadarskaMetodaMunkres::new(&matrica);
-        mm.assignment_mask.matrix[0][0] = 1;
-        mm.assignment_mask.matrix[0][1] = 2;
-        mm.assignment_mask.matrix[1][0] = 2;
+        mm.assignment_mask[0][0] = 1;
+        mm.assignment_mask[0][1] = 2;
+        mm.assignment_mask[1][0] = 2;
 
         assert_eq!(1, mm.get_prime_column_index(0).unwrap());
         assert_eq!(0, mm.get_prime_column_index(1).unwrap());
@@ -1596,9 +2200,9 @@ mod tests {
         ]);
 
         let mut mm = MadarskaMetodaMunkres::new(&matrica);
-        mm.assignment_mask.matrix[0][0] = 1;
-        mm.assignment_mask.matrix[0][1] = 2;
-        mm.assignment_mask.matrix[1][0] = 2;
+        mm.assignment_mask[0][0] = 1;
+        mm.assignment_mask[0][1] = 2;
+        mm.assignment_mask[1][0] = 2;
 
         mm.path.path_count = 3;
         mm.path.path[0][0] = 1;
@@ -1614,7 +2218,7 @@ mod tests {
             vec![0, 0, 0],
         ];
 
-        assert_eq!(expected_assignment, mm.assignment_mask.matrix);
+        assert_eq!(expected_assignment, mm.assignment_mask.to_vec());
     }
 
     #[test]
@@ -1626,9 +2230,9 @@ mod tests {
         ]);
 
         let mut mm = MadarskaMetodaMunkres::new(&matrica);
-        mm.assignment_mask.matrix[0][0] = 1;
-        mm.assignment_mask.matrix[0][1] = 2;
-        mm.assignment_mask.matrix[1][0] = 2;
+        mm.assignment_mask[0][0] = 1;
+        mm.assignment_mask[0][1] = 2;
+        mm.assignment_mask[1][0] = 2;
         mm.path.starting_row = 1;
         mm.path.starting_column = 0;
 
@@ -1640,7 +2244,7 @@ mod tests {
             vec![0, 0, 0],
         ];
 
-        assert_eq!(expected_assignment, mm.assignment_mask.matrix);
+        assert_eq!(expected_assignment, mm.assignment_mask.to_vec());
     }
 
     #[test]
@@ -1674,8 +2278,459 @@ mod tests {
             vec![0, 2, 4],
         ];
 
-        mm.sixth_step();
+        mm.sixth_step().unwrap();
 
-        assert_eq!(expected_matrix, mm.calculating_matrix.matrix);
+        assert_eq!(expected_matrix, mm.calculating_matrix.to_vec());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn assignment_test() {
+        let matrica = Matrix::new(vec![
+            vec![4, 6, 5, 3, 1],
+            vec![4, 2, 7, 3, 1],
+            vec![5, 3, 9, 5, 1],
+        ]);
+
+        let mut mm = MadarskaMetoda::new(&matrica);
+        mm.solve(None);
+        let assignment = mm.assignment();
+        assert_eq!(3, assignment.len());
+        assert_eq!(6, assignment.iter().map(|&(r, c)| matrica[r][c]).sum::<i32>());
+
+        let mut mm = MadarskaMetodaMunkres::new(&matrica);
+        mm.solve(None);
+        let assignment = mm.assignment();
+        assert_eq!(3, assignment.len());
+        assert_eq!(6, assignment.iter().map(|&(r, c)| matrica[r][c]).sum::<i32>());
+    }
+
+    #[test]
+    fn labelled_assignment_test() {
+        let matrica = Matrix::new(vec![
+            vec![4, 6, 5, 3, 1],
+            vec![4, 2, 7, 3, 1],
+            vec![5, 3, 9, 5, 1],
+        ]).with_labels(
+            vec!["Ana".to_owned(), "Ivan".to_owned(), "Marko".to_owned()],
+            (1..=5).map(|c| format!("T{}", c)).collect(),
+        ).unwrap();
+
+        let expected = vec![
+            Assignment { row: 0, column: 3, cost: 3, row_label: Some("Ana".to_owned()), column_label: Some("T4".to_owned()) },
+            Assignment { row: 1, column: 1, cost: 2, row_label: Some("Ivan".to_owned()), column_label: Some("T2".to_owned()) },
+            Assignment { row: 2, column: 4, cost: 1, row_label: Some("Marko".to_owned()), column_label: Some("T5".to_owned()) },
+        ];
+
+        let mut mm = MadarskaMetoda::new(&matrica);
+        assert_eq!(6, mm.solve(None));
+        assert_eq!(expected, mm.labelled_assignment());
+
+        let mut mm = MadarskaMetodaMunkres::new(&matrica);
+        assert_eq!(6, mm.solve(None));
+        assert_eq!(expected, mm.labelled_assignment());
+        assert_eq!(None, mm.starting_matrix.row_label(3));
+
+        let transformed = matrica.max_offset().unwrap();
+        assert_eq!(matrica.row_labels, transformed.row_labels);
+        assert_eq!(matrica.column_labels, transformed.column_labels);
+
+        let unlabelled = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
+        assert!(unlabelled.clone().with_labels(vec!["a".to_owned()], vec!["x".to_owned(), "y".to_owned()]).is_err());
+        assert!(unlabelled.with_labels(vec!["a".to_owned(), "b".to_owned()], Vec::new()).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let matrica = Matrix::new(vec![
+            vec![1, 2, 3],
+            vec![2, 4, 6],
+        ]).with_labels(
+            vec!["a".to_owned(), "b".to_owned()],
+            vec!["x".to_owned(), "y".to_owned(), "z".to_owned()],
+        ).unwrap();
+
+        let json = serde_json::to_string(&matrica).unwrap();
+        assert_eq!(matrica, serde_json::from_str::<Matrix>(&json).unwrap());
+        for broken in [
+            r#"{"rows":2,"columns":2,"data":[1,2,3],"original_rows":2,"original_columns":2,"row_labels":null,"column_labels":null}"#,
+            r#"{"rows":2,"columns":2,"data":[1,2,3,4],"original_rows":3,"original_columns":2,"row_labels":null,"column_labels":null}"#,
+            r#"{"rows":2,"columns":2,"data":[1,2,3,4],"original_rows":2,"original_columns":3,"row_labels":null,"column_labels":null}"#,
+            r#"{"rows":2,"columns":2,"data":[1,2,3,4],"original_rows":2,"original_columns":2,"row_labels":["a"],"column_labels":null}"#,
+        ] {
+            assert!(serde_json::from_str::<Matrix>(broken).is_err());
+        }
+
+        let mut mm = MadarskaMetodaMunkres::new(&matrica);
+        let snapshots: Vec<MunkresSnapshot> = mm.steps(None).collect::<Result<_, _>>().unwrap();
+        let json = serde_json::to_string(&snapshots).unwrap();
+        assert_eq!(snapshots, serde_json::from_str::<Vec<MunkresSnapshot>>(&json).unwrap());
+
+        let assignment = mm.labelled_assignment();
+        let json = serde_json::to_string(&assignment).unwrap();
+        assert_eq!(assignment, serde_json::from_str::<Vec<Assignment>>(&json).unwrap());
+    }
+
+    #[test]
+    fn munkres_checkpoint() {
+        let matrica = Matrix::new(vec![
+            vec![82, 83, 69, 92, 71, 35],
+            vec![77, 37, 49, 92, 18, 64],
+            vec![11, 69,  5, 86, 73, 40],
+            vec![ 8,  9, 98, 23, 57, 13],
+            vec![64, 52, 33, 17, 66, 24],
+            vec![90, 14, 61, 44,  8, 55],
+        ]);
+
+        let mut uninterrupted = MadarskaMetodaMunkres::new(&matrica);
+        let expected = uninterrupted.solve(Some(true));
+
+        let mut mm = MadarskaMetodaMunkres::new(&matrica);
+        assert_eq!(None, mm.solve_partial(Some(true), 4));
+        let checkpoint = mm.checkpoint();
+        assert_eq!(checkpoint.step(), mm.step);
+
+        #[cfg(feature = "serde")]
+        let checkpoint: MunkresCheckpoint = serde_json::from_str(&serde_json::to_string(&checkpoint).unwrap()).unwrap();
+
+        let mut resumed = MadarskaMetodaMunkres::resume(checkpoint).unwrap();
+        assert_eq!(Some(expected), resumed.solve_partial(Some(true), usize::MAX));
+        assert_eq!(uninterrupted.assignment(), resumed.assignment());
+
+        let mut broken = mm.checkpoint();
+        broken.crossed_rows.pop();
+        assert!(MadarskaMetodaMunkres::resume(broken).is_err());
+    }
+
+    #[test]
+    fn munkres_checkpoint_validation() {
+        let matrica = Matrix::new_rectangular(vec![
+            vec![4, 6, 5, 3, 1],
+            vec![4, 2, 7, 3, 1],
+            vec![5, 3, 9, 5, 1],
+        ]);
+        let mut mm = MadarskaMetodaMunkres::new(&matrica);
+        assert_eq!(None, mm.solve_partial(None, 2));
+
+        let broken: [fn(&mut MunkresCheckpoint); 12] = [
+            |c| { c.step = 5; c.path.starting_row = 99; },
+            |c| c.path.starting_column = 5,
+            |c| c.path.path[0] = [3, 0],
+            |c| c.path.path[5] = [0, 5],
+            |c| c.assignment_mask[0][0] = 3,
+            |c| c.crossed_rows[0] = 7,
+            |c| c.crossed_columns[4] = 2,
+            |c| { c.step = 6; c.crossed_rows.iter_mut().for_each(|v| *v = 1); },
+            |c| { c.assignment_mask[0][0] = 1; c.assignment_mask[0][1] = 1; },
+            |c| { c.assignment_mask[0][0] = 1; c.assignment_mask[1][0] = 1; },
+            |c| { c.step = 5; c.assignment_mask = Matrix::new_empty(3, 5); },
+            |c| {
+                // Prime, star, prime, star, prime, star, ... never ends.
+                c.step = 5;
+                c.path.starting_row = 0;
+                c.path.starting_column = 0;
+                c.assignment_mask = Matrix::new_rectangular(vec![
+                    vec![2, 0, 0, 0, 0],
+                    vec![1, 2, 0, 0, 0],
+                    vec![2, 1, 0, 0, 0],
+                ]);
+            },
+        ];
+        for breaks in broken.iter() {
+            let mut checkpoint = mm.checkpoint();
+            breaks(&mut checkpoint);
+            assert!(MadarskaMetodaMunkres::resume(checkpoint).is_err());
+        }
+        assert!(MadarskaMetodaMunkres::resume(mm.checkpoint()).is_ok());
+    }
+
+    #[test]
+    fn solve_limits() {
+        use crate::limits::{CancellationToken, LimitReason};
+        use std::time::Duration;
+
+        let matrica = Matrix::new(vec![
+            vec![82, 83, 69, 92, 71, 35],
+            vec![77, 37, 49, 92, 18, 64],
+            vec![11, 69,  5, 86, 73, 40],
+            vec![ 8,  9, 98, 23, 57, 13],
+            vec![64, 52, 33, 17, 66, 24],
+            vec![90, 14, 61, 44,  8, 55],
+        ]);
+        let optimum = MadarskaMetodaMunkres::new(&matrica).solve(None);
+
+        let options = SolveOptions::new().max_iterations(3);
+        match MadarskaMetodaMunkres::new(&matrica).solve_with_options(None, &options) {
+            Err(SolveError::LimitReached { reason, partial, bound }) => {
+                assert_eq!(LimitReason::Iterations, reason);
+                assert!(!partial.is_empty());
+                assert!(bound <= optimum);
+            },
+            other => panic!("Expected iteration limit, got {:?}", other),
+        }
+        match MadarskaMetoda::new(&matrica).solve_with_options(None, &SolveOptions::new().max_iterations(1)) {
+            Err(SolveError::LimitReached { reason, bound, .. }) => {
+                assert_eq!(LimitReason::Iterations, reason);
+                assert!(bound <= optimum);
+            },
+            other => panic!("Expected iteration limit, got {:?}", other),
+        }
+
+        let max_optimum = MadarskaMetodaMunkres::new(&matrica).solve(Some(true));
+        match MadarskaMetodaMunkres::new(&matrica).solve_with_options(Some(true), &options) {
+            Err(SolveError::LimitReached { bound, .. }) => assert!(bound >= max_optimum),
+            other => panic!("Expected iteration limit, got {:?}", other),
+        }
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let options = SolveOptions::new().cancellation(token);
+        match MadarskaMetoda::new(&matrica).solve_with_options(None, &options) {
+            Err(SolveError::LimitReached { reason, partial, .. }) => {
+                assert_eq!(LimitReason::Cancelled, reason);
+                assert!(partial.is_empty());
+            },
+            other => panic!("Expected cancellation, got {:?}", other),
+        }
+
+        let options = SolveOptions::new().time_limit(Duration::from_secs(0));
+        match MadarskaMetodaMunkres::new(&matrica).solve_with_options(None, &options) {
+            Err(SolveError::LimitReached { reason, .. }) => assert_eq!(LimitReason::Deadline, reason),
+            other => panic!("Expected deadline, got {:?}", other),
+        }
+
+        let options = SolveOptions::new().time_limit(Duration::from_secs(60)).max_iterations(10_000);
+        assert_eq!(Ok(optimum), MadarskaMetoda::new(&matrica).solve_with_options(None, &options));
+        assert_eq!(Ok(optimum), MadarskaMetodaMunkres::new(&matrica).solve_with_options(None, &options));
+    }
+
+    #[test]
+    fn solve_stats() {
+        let matrica = Matrix::new(vec![
+            vec![82, 83, 69, 92, 71, 35],
+            vec![77, 37, 49, 92, 18, 64],
+            vec![11, 69,  5, 86, 73, 40],
+            vec![ 8,  9, 98, 23, 57, 13],
+            vec![64, 52, 33, 17, 66, 24],
+            vec![90, 14, 61, 44,  8, 55],
+        ]);
+
+        let (result, stats) = MadarskaMetoda::solve_timed(&matrica, None);
+        assert_eq!(MadarskaMetodaMunkres::new(&matrica).solve(None), result);
+        assert!(stats.reductions >= 1);
+        assert!(stats.covering_iterations >= 1);
+        assert_eq!(36, stats.peak_matrix_size);
+        assert!(stats.elapsed >= stats.reduction_time + stats.assignment_time + stats.covering_time + stats.adjustment_time);
+
+        let (munkres_result, stats) = MadarskaMetodaMunkres::solve_timed(&matrica, None);
+        assert_eq!(result, munkres_result);
+        assert!(stats.reductions >= 1);
+        assert!(stats.augmentations >= 1);
+        assert!(stats.covering_iterations >= 1);
+        assert_eq!(36, stats.peak_matrix_size);
+        assert_eq!(stats.elapsed, stats.reduction_time + stats.assignment_time + stats.covering_time + stats.adjustment_time);
+    }
+
+    #[test]
+    fn munkres_steps() {
+        let matrica = Matrix::new(vec![
+            vec![1, 2, 3],
+            vec![2, 4, 6],
+            vec![3, 6, 9],
+        ]);
+
+        let mut mm = MadarskaMetodaMunkres::new(&matrica);
+        let snapshots: Vec<MunkresSnapshot> = mm.steps(None).collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(1, snapshots[0].step);
+        assert_eq!(2, snapshots[0].next_step);
+        assert_eq!(vec![
+            vec![0, 1, 2],
+            vec![0, 2, 4],
+            vec![0, 3, 6],
+        ], snapshots[0].calculating_matrix.to_vec());
+        assert_eq!(7, snapshots.last().unwrap().next_step);
+        assert!(snapshots.iter().filter(|s| s.step == 5).all(|s| !s.path.is_empty()));
+        assert!(mm.step_once().is_none());
+        assert_eq!(Some(10), mm.result());
+        assert_eq!(10, MadarskaMetodaMunkres::new(&matrica).solve(None));
+    }
+
+    #[test]
+    fn munkres_steps_max() {
+        let matrica = Matrix::new(vec![
+            vec![1, 2, 3],
+            vec![2, 4, 6],
+            vec![3, 6, 9],
+        ]);
+
+        let mut mm = MadarskaMetodaMunkres::new(&matrica);
+        assert_eq!(None, mm.result());
+        assert!(mm.steps(Some(true)).all(|snapshot| snapshot.is_ok()));
+        assert_eq!(Some(MadarskaMetodaMunkres::new(&matrica).solve(Some(true))), mm.result());
+    }
+
+    #[test]
+    fn overflow_test() {
+        let matrica = Matrix::new(vec![
+            vec![i32::MAX, 0],
+            vec![0, i32::MAX],
+        ]);
+        assert_eq!(Err(SolveError::Overflow), MadarskaMetoda::new(&matrica).solve_with_options(Some(true), &SolveOptions::new()));
+        assert_eq!(Err(SolveError::Overflow), MadarskaMetodaMunkres::new(&matrica).solve_with_options(Some(true), &SolveOptions::new()));
+        assert_eq!(Err(SolveError::Overflow), MadarskaMetodaPotentials::new(&matrica).solve_with_options(Some(true), &SolveOptions::new()));
+        assert_eq!(-1, MadarskaMetodaMunkres::new(&matrica).solve(Some(true)));
+
+        let matrica = Matrix::new(vec![
+            vec![i32::MIN, 0],
+            vec![0, 0],
+        ]);
+        assert_eq!(Err(SolveError::Overflow), MadarskaMetodaMunkres::new(&matrica).solve_with_options(Some(true), &SolveOptions::new()));
+        let steps: Vec<_> = MadarskaMetodaMunkres::new(&matrica).steps(Some(true)).collect();
+        assert_eq!(vec![Err(SolveError::Overflow)], steps);
+
+        assert_eq!(0, MadarskaMetodaPotentials::new(&matrica).solve(Some(true)));
+        assert_eq!(i32::MIN, MadarskaMetodaPotentials::new(&matrica).solve(None));
+
+        let matrica = Matrix::new(vec![
+            vec![i32::MIN, i32::MAX],
+            vec![0, 0],
+        ]);
+        let mut mm = MadarskaMetodaMunkres::new(&matrica);
+        assert_eq!(Some(Err(SolveError::Overflow)), mm.step_once());
+        assert_eq!(Some(Err(SolveError::Overflow)), mm.step_once());
+        assert_eq!(None, mm.result());
+    }
+
+    #[test]
+    fn munkres_rectangular() {
+        let wide = vec![
+            vec![4, 6, 5, 3, 1],
+            vec![4, 2, 7, 3, 1],
+            vec![5, 3, 9, 5, 1],
+        ];
+        let tall = (0..5).map(|col| wide.iter().map(|row| row[col]).collect()).collect();
+
+        for data in [wide, tall] {
+            let matrica = Matrix::new_rectangular(data.clone());
+            assert_eq!((data.len(), data[0].len()), (matrica.rows(), matrica.columns()));
+            assert_eq!((matrica.rows(), matrica.columns()), (matrica.original_rows(), matrica.original_columns()));
+
+            let mut mm = MadarskaMetodaMunkres::new(&matrica);
+            assert_eq!(6, mm.solve(None));
+            assert_eq!(3, mm.assignment().len());
+            assert!(reference::is_valid_assignment(&matrica, &mm.assignment()));
+            assert_eq!(19, MadarskaMetodaMunkres::new(&matrica).solve(Objective::Maximize));
+
+            let mut mm = MadarskaMetodaMunkres::new(&matrica);
+            assert_eq!(None, mm.solve_partial(None, 2));
+            let mut resumed = MadarskaMetodaMunkres::resume(mm.checkpoint()).unwrap();
+            assert_eq!(6, resumed.solve(None));
+
+            let limited = MadarskaMetodaMunkres::new(&matrica).solve_with_options(None, &SolveOptions::new().max_iterations(1));
+            match limited {
+                Err(SolveError::LimitReached { bound, .. }) => assert!(bound <= 6),
+                other => panic!("Expected iteration limit, got {:?}", other),
+            }
+        }
+    }
+
+    fn near_bounds() -> impl Strategy<Value = i32> {
+        prop_oneof![
+            i32::MIN..i32::MIN + 8,
+            -8..8,
+            i32::MAX - 8..=i32::MAX,
+            any::<i32>(),
+        ]
+    }
+
+    fn matrix_near_bounds() -> impl Strategy<Value = Matrix> {
+        (1usize..5).prop_flat_map(|n| {
+            prop::collection::vec(near_bounds(), n * n).prop_map(move |data| Matrix::from_flat(n, n, data))
+        })
+    }
+
+    /// Values that keep every reduced cost and the optimum of an `n` by `n`
+    /// matrix in `i32`, when maximizing too.
+    fn comfortable_limit(n: usize) -> i32 {
+        i32::MAX / (2 * n as i32)
+    }
+
+    fn matrix_comfortable() -> impl Strategy<Value = Matrix> {
+        (1usize..5).prop_flat_map(|n| {
+            let limit = comfortable_limit(n);
+            let values = prop_oneof![-limit..=-limit + 8, -8..8, limit - 8..=limit, -limit..=limit];
+            prop::collection::vec(values, n * n).prop_map(move |data| Matrix::from_flat(n, n, data))
+        })
+    }
+
+    /// The step solvers reduce in `i32` and may overflow on the way to a result
+    /// that fits, but not when every value is within `comfortable_limit`.
+    fn step_solver_near_bounds(matrica: &Matrix, maximize: bool, result: Result<i32, SolveError>) -> Result<(), TestCaseError> {
+        let expected = reference::brute_force(matrica, maximize).unwrap().cost;
+        let limit = comfortable_limit(matrica.rows()).unsigned_abs();
+        match result {
+            Ok(result) => prop_assert_eq!(expected, result as i64),
+            Err(error) => {
+                prop_assert_eq!(SolveError::Overflow, error);
+                prop_assert!(matrica.as_slice().iter().any(|v| v.unsigned_abs() > limit), "{:?}", matrica.to_vec());
+            },
+        }
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn potentials_near_bounds(matrica in matrix_near_bounds(), maximize in any::<bool>()) {
+            let expected = i32::try_from(reference::brute_force(&matrica, maximize).unwrap().cost).map_err(|_| SolveError::Overflow);
+            let result = MadarskaMetodaPotentials::new(&matrica).solve_with_options(Some(maximize), &SolveOptions::new());
+            prop_assert_eq!(expected, result);
+        }
+
+        #[test]
+        fn munkres_resume_every_step(
+            data in (1usize..6, 1usize..6).prop_flat_map(|(rows, columns)| prop::collection::vec(prop::collection::vec(0..6, columns), rows)),
+            maximize in any::<bool>(),
+        ) {
+            let matrica = Matrix::new_rectangular(data);
+            let expected = MadarskaMetodaMunkres::new(&matrica).solve(maximize);
+            let mut mm = MadarskaMetodaMunkres::new(&matrica);
+            while mm.solve_partial(maximize, 1).is_none() {
+                let mut resumed = MadarskaMetodaMunkres::resume(mm.checkpoint()).unwrap();
+                prop_assert_eq!(expected, resumed.solve(maximize));
+            }
+        }
+
+        #[test]
+        fn munkres_rectangular_matches_padded(
+            data in (1usize..7, 1usize..7).prop_flat_map(|(rows, columns)| prop::collection::vec(prop::collection::vec(-50..50, columns), rows)),
+            maximize in any::<bool>(),
+        ) {
+            let rectangular = Matrix::new_rectangular(data.clone());
+            let expected = reference::brute_force(&Matrix::new(data), maximize).unwrap().cost;
+            let mut mm = MadarskaMetodaMunkres::new(&rectangular);
+            prop_assert_eq!(Ok(expected as i32), mm.solve_with_options(Some(maximize), &SolveOptions::new()));
+            prop_assert!(reference::is_valid_assignment(&rectangular, &mm.assignment()));
+        }
+
+        #[test]
+        fn munkres_near_bounds(matrica in matrix_near_bounds(), maximize in any::<bool>()) {
+            let result = MadarskaMetodaMunkres::new(&matrica).solve_with_options(Some(maximize), &SolveOptions::new());
+            step_solver_near_bounds(&matrica, maximize, result)?;
+        }
+
+        #[test]
+        fn classic_near_bounds(matrica in matrix_near_bounds(), maximize in any::<bool>()) {
+            let result = MadarskaMetoda::new(&matrica).solve_with_options(Some(maximize), &SolveOptions::new());
+            step_solver_near_bounds(&matrica, maximize, result)?;
+        }
+
+        #[test]
+        fn step_solvers_comfortable(matrica in matrix_comfortable(), maximize in any::<bool>()) {
+            let expected = reference::brute_force(&matrica, maximize).unwrap().cost as i32;
+            let options = SolveOptions::new();
+            prop_assert_eq!(Ok(expected), MadarskaMetoda::new(&matrica).solve_with_options(Some(maximize), &options));
+            prop_assert_eq!(Ok(expected), MadarskaMetodaMunkres::new(&matrica).solve_with_options(Some(maximize), &options));
+        }
+    }
+}
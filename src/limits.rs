@@ -0,0 +1,138 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::{Matrix, Objective};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Shared flag that stops a running solve when cancelled from another thread.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SolveOptions {
+    pub deadline: Option<Instant>,
+    pub max_iterations: Option<usize>,
+    pub cancellation: Option<CancellationToken>,
+}
+
+impl SolveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    pub fn time_limit(self, limit: Duration) -> Self {
+        self.deadline(Instant::now() + limit)
+    }
+
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    pub(crate) fn check(&self, iterations: usize) -> Option<LimitReason> {
+        if self.cancellation.as_ref().is_some_and(CancellationToken::is_cancelled) {
+            return Some(LimitReason::Cancelled);
+        }
+        if self.max_iterations.is_some_and(|max| iterations >= max) {
+            return Some(LimitReason::Iterations);
+        }
+        if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return Some(LimitReason::Deadline);
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LimitReason {
+    Deadline,
+    Iterations,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SolveError {
+    /// The solve was stopped early. `partial` holds the independent assignments
+    /// found so far and `bound` is the best known bound on the optimum, a lower
+    /// bound when minimizing and an upper bound when maximizing.
+    LimitReached {
+        reason: LimitReason,
+        partial: Vec<(usize, usize)>,
+        bound: i32,
+    },
+    /// A cost, an intermediate value of the solve or the total cost does not fit in `i32`.
+    Overflow,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::LimitReached { reason, partial, bound } => {
+                let reason = match reason {
+                    LimitReason::Deadline => "deadline passed",
+                    LimitReason::Iterations => "iteration limit reached",
+                    LimitReason::Cancelled => "solve was cancelled",
+                };
+                write!(f, "{} with {} assignments made, optimum bound is {}", reason, partial.len(), bound)
+            },
+            SolveError::Overflow => write!(f, "cost values overflow 32-bit arithmetic"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// Bound on the optimum from a reduced matrix. The solvers only subtract
+/// constants from whole rows and columns, so the difference between the
+/// original and reduced diagonal adds up to the total reduction. Rectangular
+/// matrices have no such diagonal and fall back to the cheapest cell of every
+/// line on the shorter side.
+pub(crate) fn reduction_bound(starting: &Matrix, calculating: &Matrix, objective: Objective, offset: i32, reduced: bool) -> i32 {
+    let cost = |row, col| objective.cost(starting, offset, row, col);
+    let bound: i64 = if reduced && calculating.rows() == calculating.columns() {
+        (0..calculating.rows()).map(|i| cost(i, i) - calculating[i][i] as i64).sum()
+    } else if calculating.rows() <= calculating.columns() {
+        (0..calculating.rows()).map(|row| (0..calculating.columns()).map(|col| cost(row, col)).min().unwrap_or(0)).sum()
+    } else {
+        (0..calculating.columns()).map(|col| (0..calculating.rows()).map(|row| cost(row, col)).min().unwrap_or(0)).sum()
+    };
+    clamp_to_i32(objective.original_bound(starting, offset, bound))
+}
+
+/// Bounds are still useful when they do not fit, so they saturate instead of failing.
+pub(crate) fn clamp_to_i32(value: i64) -> i32 {
+    value.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
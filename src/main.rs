@@ -0,0 +1,212 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+use madarska_metoda::limits::SolveOptions;
+use madarska_metoda::{MadarskaMetoda, MadarskaMetodaMunkres, MadarskaMetodaPotentials, Matrix, Objective};
+
+const USAGE: &str = "Usage: madarska [OPTIONS] [FILE]
+
+Solves the assignment problem for the cost matrix in FILE (or stdin when FILE
+is missing or '-'). Values may be separated by commas, tabs or whitespace.
+
+Options:
+      --max              Maximize the total cost instead of minimizing it
+      --algo ALGORITHM   potentials (default), munkres or classic
+      --format FORMAT    text (default) or json
+  -h, --help             Print this help
+
+Rows and columns of the assignment are numbered from 1 in both formats.";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Algorithm {
+    Potentials,
+    Munkres,
+    Classic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    input: Option<String>,
+    maximize: bool,
+    algorithm: Algorithm,
+    format: Format,
+    help: bool,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Options, String> {
+    let mut options = Options {
+        input: None,
+        maximize: false,
+        algorithm: Algorithm::Potentials,
+        format: Format::Text,
+        help: false,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => options.help = true,
+            "--max" => options.maximize = true,
+            "--algo" => {
+                options.algorithm = match args.next().as_deref() {
+                    Some("potentials") => Algorithm::Potentials,
+                    Some("munkres") => Algorithm::Munkres,
+                    Some("classic") => Algorithm::Classic,
+                    Some(other) => return Err(format!("unknown algorithm '{}', expected potentials, munkres or classic", other)),
+                    None => return Err("--algo requires a value".to_owned()),
+                }
+            },
+            "--format" => {
+                options.format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some(other) => return Err(format!("unknown format '{}', expected text or json", other)),
+                    None => return Err("--format requires a value".to_owned()),
+                }
+            },
+            "-" => options.input = None,
+            other if other.starts_with('-') => return Err(format!("unknown option '{}'", other)),
+            other => {
+                if options.input.is_some() {
+                    return Err("only one input file can be given".to_owned());
+                }
+                options.input = Some(other.to_owned());
+            },
+        }
+    }
+
+    Ok(options)
+}
+
+fn read_input(input: &Option<String>) -> Result<String, String> {
+    match input {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("cannot read '{}': {}", path, e)),
+        None => {
+            let mut buffer = String::new();
+            io::stdin()
+                .read_to_string(&mut buffer)
+                .map_err(|e| format!("cannot read stdin: {}", e))?;
+            Ok(buffer)
+        },
+    }
+}
+
+fn solve(matrix: &Matrix, options: &Options) -> Result<(i32, Vec<(usize, usize)>), String> {
+    let objective = Objective::from(options.maximize);
+    let solve_options = SolveOptions::new();
+    let (cost, assignment) = match options.algorithm {
+        Algorithm::Potentials => {
+            let mut mm = MadarskaMetodaPotentials::new(matrix);
+            let cost = mm.solve_with_options(objective, &solve_options).map_err(|e| e.to_string())?;
+            (cost, mm.assignment())
+        },
+        Algorithm::Munkres => {
+            let mut mm = MadarskaMetodaMunkres::new(matrix);
+            let cost = mm.solve_with_options(objective, &solve_options).map_err(|e| e.to_string())?;
+            (cost, mm.assignment())
+        },
+        Algorithm::Classic => {
+            let mut mm = MadarskaMetoda::new(matrix);
+            let cost = mm.solve_with_options(objective, &solve_options).map_err(|e| e.to_string())?;
+            (cost, mm.assignment())
+        },
+    };
+
+    if assignment.len() != matrix.original_rows().min(matrix.original_columns()) {
+        return Err("no complete assignment was found".to_owned());
+    }
+    Ok((cost, assignment))
+}
+
+fn render(matrix: &Matrix, cost: i32, assignment: &[(usize, usize)], format: Format) -> String {
+    match format {
+        Format::Text => {
+            let mut out = String::new();
+            for &(row, col) in assignment {
+                out.push_str(&format!("row {} -> column {} (cost {})\n", row + 1, col + 1, matrix[row][col]));
+            }
+            out.push_str(&format!("total cost: {}\n", cost));
+            out
+        },
+        Format::Json => {
+            let cells: Vec<String> = assignment
+                .iter()
+                .map(|&(row, col)| format!("{{\"row\":{},\"column\":{},\"cost\":{}}}", row + 1, col + 1, matrix[row][col]))
+                .collect();
+            format!("{{\"cost\":{},\"assignment\":[{}]}}\n", cost, cells.join(","))
+        },
+    }
+}
+
+fn run() -> Result<String, String> {
+    let options = parse_args(env::args().skip(1))?;
+    if options.help {
+        return Ok(format!("{}\n", USAGE));
+    }
+
+    let input = read_input(&options.input)?;
+    let matrix = Matrix::parse(&input).map_err(|e| format!("malformed input: {}", e))?;
+    let (cost, assignment) = solve(&matrix, &options)?;
+    Ok(render(&matrix, cost, &assignment, options.format))
+}
+
+fn main() {
+    match run() {
+        Ok(output) => print!("{}", output),
+        Err(message) => {
+            eprintln!("madarska: {}", message);
+            process::exit(1);
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter().map(|a| a.to_string()).collect::<Vec<String>>().into_iter()
+    }
+
+    #[test]
+    fn parse_args_test() {
+        let options = parse_args(args(&["--max", "--algo", "classic", "--format", "json", "costs.csv"])).unwrap();
+        assert_eq!(Options {
+            input: Some("costs.csv".to_owned()),
+            maximize: true,
+            algorithm: Algorithm::Classic,
+            format: Format::Json,
+            help: false,
+        }, options);
+
+        assert!(parse_args(args(&["--algo", "auction"])).is_err());
+        assert!(parse_args(args(&["--format"])).is_err());
+        assert!(parse_args(args(&["--verbose"])).is_err());
+        assert!(parse_args(args(&["a.csv", "b.csv"])).is_err());
+    }
+
+    #[test]
+    fn solve_and_render() {
+        let matrix = Matrix::parse("4,6,5,3,1\n4,2,7,3,1\n5,3,9,5,1\n").unwrap();
+        let options = parse_args(args(&["--format", "json"])).unwrap();
+        let (cost, assignment) = solve(&matrix, &options).unwrap();
+
+        assert_eq!(6, cost);
+        assert_eq!(3, assignment.len());
+        assert_eq!(
+            "{\"cost\":6,\"assignment\":[{\"row\":1,\"column\":4,\"cost\":3},{\"row\":2,\"column\":2,\"cost\":2},{\"row\":3,\"column\":5,\"cost\":1}]}\n",
+            render(&matrix, cost, &assignment, Format::Json)
+        );
+        assert_eq!(
+            "row 1 -> column 4 (cost 3)\nrow 2 -> column 2 (cost 2)\nrow 3 -> column 5 (cost 1)\ntotal cost: 6\n",
+            render(&matrix, cost, &assignment, Format::Text)
+        );
+    }
+}
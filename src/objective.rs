@@ -0,0 +1,124 @@
+use crate::limits::SolveError;
+use crate::Matrix;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Direction of the optimization. `Option<bool>` and `bool` convert into it,
+/// `Some(true)` and `true` meaning maximize, so older call sites keep working.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Objective {
+    #[default]
+    Minimize,
+    Maximize,
+}
+
+impl From<Option<bool>> for Objective {
+    fn from(maximize: Option<bool>) -> Self {
+        maximize.unwrap_or(false).into()
+    }
+}
+
+impl From<bool> for Objective {
+    fn from(maximize: bool) -> Self {
+        if maximize { Objective::Maximize } else { Objective::Minimize }
+    }
+}
+
+impl Objective {
+    pub fn is_maximize(self) -> bool {
+        self == Objective::Maximize
+    }
+
+    /// Cell of the minimization problem the solvers work on, `offset` is the
+    /// largest real value of `matrix` when maximizing.
+    pub(crate) fn cost(self, matrix: &Matrix, offset: i32, row: usize, col: usize) -> i64 {
+        match self {
+            Objective::Minimize => matrix[row][col] as i64,
+            Objective::Maximize if matrix.is_dummy(row, col) => 0,
+            Objective::Maximize => offset as i64 - matrix[row][col] as i64,
+        }
+    }
+
+    /// Converts a bound of the minimization problem back to a bound on `matrix`.
+    /// Every complete assignment uses the same number of real cells, so each of
+    /// them contributes `offset` once.
+    pub(crate) fn original_bound(self, matrix: &Matrix, offset: i32, bound: i64) -> i64 {
+        match self {
+            Objective::Minimize => bound,
+            Objective::Maximize => {
+                let real_cells = matrix.original_rows().min(matrix.original_columns()) as i64;
+                real_cells * offset as i64 - bound
+            },
+        }
+    }
+}
+
+impl Matrix {
+    /// Largest value outside the padded dummy cells.
+    pub(crate) fn real_max(&self) -> i32 {
+        let mut max = None;
+        for row in 0..self.rows() {
+            for col in 0..self.columns() {
+                if !self.is_dummy(row, col) && max.is_none_or(|m| self[row][col] > m) {
+                    max = Some(self[row][col]);
+                }
+            }
+        }
+        max.unwrap_or(0)
+    }
+
+    /// Minimization costs with the same optimal assignments as maximizing this
+    /// matrix. Every real value becomes `max - value` and dummy cells stay 0, so
+    /// padding never changes which real cells are chosen.
+    pub fn max_offset(&self) -> Result<Matrix, SolveError> {
+        let offset = self.real_max();
+        let mut m = self.clone();
+        for row in 0..self.rows() {
+            for col in 0..self.columns() {
+                m[row][col] = if self.is_dummy(row, col) {
+                    0
+                } else {
+                    offset.checked_sub(self[row][col]).ok_or(SolveError::Overflow)?
+                };
+            }
+        }
+        Ok(m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MadarskaMetoda, MadarskaMetodaMunkres, MadarskaMetodaPotentials};
+
+    #[test]
+    fn max_offset_transform() {
+        let matrica = Matrix::new(vec![
+            vec![1, 2, 3],
+            vec![6, 4, 5],
+        ]);
+
+        let transformed = matrica.max_offset().unwrap();
+        assert_eq!(vec![vec![5, 4, 3], vec![0, 2, 1], vec![0, 0, 0]], transformed.to_vec());
+        assert_eq!(Err(SolveError::Overflow), Matrix::new(vec![vec![i32::MIN, i32::MAX]]).max_offset());
+
+        assert_eq!(Objective::Maximize, Objective::from(Some(true)));
+        assert_eq!(Objective::Minimize, Objective::from(None));
+        assert_eq!(Objective::Minimize, Objective::from(false));
+    }
+
+    #[test]
+    fn maximize_with_padding() {
+        let matrica = Matrix::new(vec![
+            vec![-5, -1, -3],
+            vec![-2, -8, -4],
+        ]);
+
+        assert_eq!(-3, MadarskaMetoda::new(&matrica).solve(Objective::Maximize));
+        assert_eq!(-3, MadarskaMetodaMunkres::new(&matrica).solve(Objective::Maximize));
+        assert_eq!(-3, MadarskaMetodaPotentials::new(&matrica).solve(Objective::Maximize));
+        assert_eq!(-13, MadarskaMetodaMunkres::new(&matrica).solve(Objective::Minimize));
+    }
+}
@@ -0,0 +1,139 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::Matrix;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    Empty,
+    InvalidValue { line: usize, column: usize, value: String },
+    RaggedRow { line: usize, expected: usize, found: usize },
+    Overflow,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "input contains no matrix rows"),
+            ParseError::InvalidValue { line, column, value } => {
+                write!(f, "line {}, column {}: '{}' is not an integer", line, column, value)
+            },
+            ParseError::RaggedRow { line, expected, found } => {
+                write!(f, "line {}: expected {} values, found {}", line, expected, found)
+            },
+            ParseError::Overflow => write!(f, "costs are too large to penalize forbidden cells"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Delimiter {
+    Comma,
+    Tab,
+    Whitespace,
+}
+
+impl Delimiter {
+    /// Picks the delimiter from the first non empty line of the input.
+    pub fn detect(input: &str) -> Self {
+        let line = input.lines().find(|line| !line.trim().is_empty()).unwrap_or("");
+        if line.contains('\t') {
+            Delimiter::Tab
+        } else if line.contains(',') {
+            Delimiter::Comma
+        } else {
+            Delimiter::Whitespace
+        }
+    }
+
+    pub fn split(self, line: &str) -> Vec<&str> {
+        match self {
+            Delimiter::Comma => line.split(',').map(str::trim).collect(),
+            Delimiter::Tab => line.split('\t').map(str::trim).collect(),
+            Delimiter::Whitespace => line.split_whitespace().collect(),
+        }
+    }
+}
+
+impl Matrix {
+    /// Parses a cost matrix written as CSV, TSV or whitespace separated values,
+    /// one row per line. Empty lines and lines starting with `#` are skipped.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let delimiter = Delimiter::detect(input);
+        let mut data: Vec<Vec<i32>> = Vec::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let mut row = Vec::new();
+            for (column, value) in delimiter.split(line).into_iter().enumerate() {
+                match value.parse::<i32>() {
+                    Ok(v) => row.push(v),
+                    Err(_) => {
+                        return Err(ParseError::InvalidValue {
+                            line: index + 1,
+                            column: column + 1,
+                            value: value.to_owned(),
+                        })
+                    },
+                }
+            }
+
+            if let Some(first) = data.first() {
+                if first.len() != row.len() {
+                    return Err(ParseError::RaggedRow {
+                        line: index + 1,
+                        expected: first.len(),
+                        found: row.len(),
+                    });
+                }
+            }
+            data.push(row);
+        }
+
+        if data.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        Ok(Matrix::new(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_formats() {
+        let expected = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, -9]];
+
+        assert_eq!(expected, Matrix::parse("1,2,3\n4, 5, 6\n7,8,-9\n").unwrap().to_vec());
+        assert_eq!(expected, Matrix::parse("1\t2\t3\n4\t5\t6\n7\t8\t-9").unwrap().to_vec());
+        assert_eq!(expected, Matrix::parse("# costs\n1 2 3\n\n4  5 6\n7 8 -9\n").unwrap().to_vec());
+    }
+
+    #[test]
+    fn parse_rectangular() {
+        let matrica = Matrix::parse("1 2 3\n4 5 6\n").unwrap();
+
+        assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6], vec![0, 0, 0]], matrica.to_vec());
+        assert_eq!((2, 3), (matrica.original_rows(), matrica.original_columns()));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Err(ParseError::Empty), Matrix::parse("\n# nothing\n"));
+        assert_eq!(
+            Err(ParseError::InvalidValue { line: 2, column: 2, value: "x".to_owned() }),
+            Matrix::parse("1,2\n3,x\n")
+        );
+        assert_eq!(
+            Err(ParseError::RaggedRow { line: 2, expected: 2, found: 3 }),
+            Matrix::parse("1 2\n3 4 5\n")
+        );
+    }
+}
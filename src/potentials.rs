@@ -0,0 +1,328 @@
+use std::time::Instant;
+
+use crate::limits::{clamp_to_i32, SolveError, SolveOptions};
+use crate::stats::{Phase, SolveStats};
+use crate::{Assignment, Matrix, Objective};
+
+/// Hungarian method with row and column potentials and a slack array per
+/// column. Every row is added with a single shortest augmenting path search,
+/// so the whole solve is O(n²m) instead of the O(n⁴) worst case of the
+/// `MadarskaMetodaMunkres` step machine. Rectangular matrices are solved
+/// directly, one with more rows than columns is worked on transposed so the
+/// rows are always the shorter side. Potentials are kept in `i64`, so any
+/// `i32` costs can be solved as long as the optimal total fits in `i32`. A
+/// solver can be `reset` with another matrix to solve many problems without
+/// reallocating its buffers.
+pub struct MadarskaMetodaPotentials {
+    pub starting_matrix: Matrix,
+    pub assignment_mask: Matrix,
+    objective: Objective,
+    offset: i32,
+    transposed: bool,
+    row_potentials: Vec<i64>,
+    column_potentials: Vec<i64>,
+    column_match: Vec<usize>,
+    way: Vec<usize>,
+    min_slack: Vec<i64>,
+    used: Vec<bool>,
+    stats: SolveStats,
+}
+
+impl Default for MadarskaMetodaPotentials {
+    fn default() -> Self {
+        Self::new(Matrix::new_empty(0, 0))
+    }
+}
+
+impl MadarskaMetodaPotentials {
+    /// Takes a `&Matrix`, a `MatrixView` or an owned matrix.
+    pub fn new<M: Into<Matrix>>(matrica: M) -> Self {
+        let mut mm = Self {
+            starting_matrix: matrica.into(),
+            assignment_mask: Matrix::new_empty(0, 0),
+            objective: Objective::Minimize,
+            offset: 0,
+            transposed: false,
+            row_potentials: Vec::new(),
+            column_potentials: Vec::new(),
+            column_match: Vec::new(),
+            way: Vec::new(),
+            min_slack: Vec::new(),
+            used: Vec::new(),
+            stats: SolveStats::default(),
+        };
+        mm.prepare();
+        mm
+    }
+
+    /// Prepares the solver for `matrica`, keeping the capacity of the buffers
+    /// from earlier solves.
+    pub fn reset(&mut self, matrica: &Matrix) {
+        self.starting_matrix.clone_from(matrica);
+        self.prepare();
+    }
+
+    fn prepare(&mut self) {
+        let (rows, columns) = (self.starting_matrix.rows(), self.starting_matrix.columns());
+        self.transposed = rows > columns;
+        let (n, m) = (rows.min(columns), rows.max(columns));
+
+        self.assignment_mask.reset_zeroed(rows, columns);
+        self.row_potentials.clear();
+        self.row_potentials.resize(n + 1, 0);
+        for buffer in [&mut self.column_potentials, &mut self.min_slack] {
+            buffer.clear();
+            buffer.resize(m + 1, 0);
+        }
+        for buffer in [&mut self.column_match, &mut self.way] {
+            buffer.clear();
+            buffer.resize(m + 1, 0);
+        }
+        self.used.clear();
+        self.used.resize(m + 1, false);
+        self.stats = SolveStats::default();
+    }
+
+    /// Rows and columns of the problem the potentials work on, `rows <= columns`.
+    fn dimensions(&self) -> (usize, usize) {
+        let (rows, columns) = (self.starting_matrix.rows(), self.starting_matrix.columns());
+        if self.transposed { (columns, rows) } else { (rows, columns) }
+    }
+
+    /// Cell of `starting_matrix` behind 1-based `row` and `col` of the working problem.
+    fn cell(&self, row: usize, col: usize) -> (usize, usize) {
+        if self.transposed { (col - 1, row - 1) } else { (row - 1, col - 1) }
+    }
+
+    fn cost(&self, row: usize, col: usize) -> i64 {
+        let (row, col) = self.cell(row, col);
+        self.objective.cost(&self.starting_matrix, self.offset, row, col)
+    }
+
+    fn augment_row(&mut self, row: usize) {
+        let (_, m) = self.dimensions();
+        self.column_match[0] = row;
+        let mut col0 = 0;
+        for j in 0..=m {
+            self.min_slack[j] = i64::MAX;
+            self.used[j] = false;
+        }
+
+        loop {
+            self.used[col0] = true;
+            self.stats.covering_iterations += 1;
+            let row0 = self.column_match[col0];
+            let mut delta = i64::MAX;
+            let mut col1 = 0;
+
+            for j in 1..=m {
+                if self.used[j] { continue; }
+                let slack = self.cost(row0, j) - self.row_potentials[row0] - self.column_potentials[j];
+                if slack < self.min_slack[j] {
+                    self.min_slack[j] = slack;
+                    self.way[j] = col0;
+                }
+                if self.min_slack[j] < delta {
+                    delta = self.min_slack[j];
+                    col1 = j;
+                }
+            }
+
+            for j in 0..=m {
+                if self.used[j] {
+                    self.row_potentials[self.column_match[j]] += delta;
+                    self.column_potentials[j] -= delta;
+                } else {
+                    self.min_slack[j] -= delta;
+                }
+            }
+
+            col0 = col1;
+            if self.column_match[col0] == 0 { break; }
+        }
+
+        loop {
+            let col1 = self.way[col0];
+            self.column_match[col0] = self.column_match[col1];
+            col0 = col1;
+            if col0 == 0 { break; }
+        }
+    }
+
+    fn update_assignment_mask(&mut self) {
+        self.assignment_mask.data.iter_mut().for_each(|v| *v = 0);
+        for col in 1..self.column_match.len() {
+            let row = self.column_match[col];
+            if row != 0 {
+                let (row, col) = self.cell(row, col);
+                self.assignment_mask[row][col] = 1;
+            }
+        }
+    }
+
+    /// Lower bound on the minimization optimum from the current potentials,
+    /// rows that are not matched yet get the smallest reduced cost in their row.
+    /// Index 0 is the search root and not a real column, so its potential is
+    /// left out.
+    fn bound(&self, matched_rows: usize) -> i32 {
+        let (n, m) = self.dimensions();
+        let mut bound: i64 = self.column_potentials[1..].iter().sum();
+        for row in 1..=n {
+            if row <= matched_rows {
+                bound += self.row_potentials[row];
+            } else {
+                bound += (1..=m).map(|j| self.cost(row, j) - self.column_potentials[j]).min().unwrap_or(0);
+            }
+        }
+        clamp_to_i32(self.objective.original_bound(&self.starting_matrix, self.offset, bound))
+    }
+
+    pub fn solve<O: Into<Objective>>(&mut self, objective: O) -> i32 {
+        self.solve_with_options(objective, &SolveOptions::default()).unwrap_or(-1)
+    }
+
+    /// Solves like `solve` but stops with `SolveError::LimitReached` once a limit
+    /// from `options` is hit, every added row counts as one iteration.
+    pub fn solve_with_options<O: Into<Objective>>(&mut self, objective: O, options: &SolveOptions) -> Result<i32, SolveError> {
+        self.prepare();
+        self.objective = objective.into();
+        self.offset = if self.objective.is_maximize() { self.starting_matrix.real_max() } else { 0 };
+        let timer = Instant::now();
+        self.stats.track_matrix(self.starting_matrix.rows(), self.starting_matrix.columns());
+
+        let (n, _) = self.dimensions();
+        for row in 1..=n {
+            if let Some(reason) = options.check(row - 1) {
+                self.update_assignment_mask();
+                self.stats.elapsed += timer.elapsed();
+                return Err(SolveError::LimitReached {
+                    reason,
+                    partial: self.assignment(),
+                    bound: self.bound(row - 1),
+                });
+            }
+
+            let augment_timer = Instant::now();
+            self.augment_row(row);
+            self.stats.record(Phase::Assignment, augment_timer.elapsed());
+            self.stats.augmentations += 1;
+        }
+
+        self.update_assignment_mask();
+        self.stats.elapsed += timer.elapsed();
+        self.starting_matrix.assignment_cost(&self.assignment_mask)
+    }
+
+    pub fn solve_timed<O: Into<Objective>>(starting_matrix: &Matrix, objective: O) -> (i32, SolveStats) {
+        let mut mm = MadarskaMetodaPotentials::new(starting_matrix);
+        let res = mm.solve(objective);
+        (res, mm.stats)
+    }
+
+    pub fn stats(&self) -> &SolveStats {
+        &self.stats
+    }
+
+    /// Assigned `(row, column)` pairs of the last solve, without padded dummy cells.
+    pub fn assignment(&self) -> Vec<(usize, usize)> {
+        self.starting_matrix.assigned_cells(&self.assignment_mask)
+    }
+
+    pub fn labelled_assignment(&self) -> Vec<Assignment> {
+        self.starting_matrix.assignments(&self.assignment_mask)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::limits::LimitReason;
+    use crate::reference;
+
+    #[test]
+    fn potentials_solve() {
+        let matrica = Matrix::new(vec![
+            vec![82, 83, 69, 92, 71, 35],
+            vec![77, 37, 49, 92, 18, 64],
+            vec![11, 69,  5, 86, 73, 40],
+            vec![ 8,  9, 98, 23, 57, 13],
+            vec![64, 52, 33, 17, 66, 24],
+            vec![90, 14, 61, 44,  8, 55],
+        ]);
+
+        let mut mm = MadarskaMetodaPotentials::new(&matrica);
+        let result = mm.solve(None);
+        assert_eq!(crate::MadarskaMetodaMunkres::new(&matrica).solve(None), result);
+
+        let assignment = mm.assignment();
+        assert_eq!(6, assignment.len());
+        assert_eq!(result, assignment.iter().map(|&(r, c)| matrica[r][c]).sum::<i32>());
+        assert_eq!(6, mm.stats().augmentations);
+
+        let mut mm = MadarskaMetodaPotentials::new(&matrica);
+        assert_eq!(crate::MadarskaMetodaMunkres::new(&matrica).solve(Some(true)), mm.solve(Some(true)));
+
+        let mut mm = MadarskaMetodaPotentials::new(&matrica);
+        assert_eq!(result, mm.solve(None));
+        assert_eq!(result, mm.solve(None));
+        assert_eq!(6, mm.stats().augmentations);
+        assert!(mm.solve_with_options(None, &SolveOptions::new().max_iterations(3)).is_err());
+        assert_eq!(result, mm.solve(None));
+        assert_eq!(assignment, mm.assignment());
+    }
+
+    #[test]
+    fn potentials_reset() {
+        let matrice = [
+            Matrix::new(vec![vec![4, 6, 5, 3, 1], vec![4, 2, 7, 3, 1], vec![5, 3, 9, 5, 1]]),
+            Matrix::new(vec![vec![1, 2], vec![2, 4]]),
+            Matrix::new(vec![vec![7]]),
+        ];
+
+        let mut mm = MadarskaMetodaPotentials::default();
+        for matrica in &matrice {
+            mm.reset(matrica);
+            let result = mm.solve(None);
+            assert_eq!(MadarskaMetodaPotentials::new(matrica).solve(None), result);
+            assert_eq!(matrica.original_rows(), mm.assignment().len());
+            assert_eq!(matrica.rows(), mm.stats().augmentations);
+        }
+    }
+
+    #[test]
+    fn potentials_rectangular() {
+        let wide = Matrix::new_rectangular(vec![
+            vec![4, 6, 5, 3, 1],
+            vec![4, 2, 7, 3, 1],
+            vec![5, 3, 9, 5, 1],
+        ]);
+        let tall = Matrix::new_rectangular((0..5).map(|col| wide.column(col).copied().collect()).collect());
+
+        for (matrica, minimum, maximum) in [(&wide, 6, 19), (&tall, 6, 19)] {
+            let mut mm = MadarskaMetodaPotentials::new(matrica);
+            assert_eq!(minimum, mm.solve(None));
+            let assignment = mm.assignment();
+            assert_eq!(3, assignment.len());
+            assert!(reference::is_valid_assignment(matrica, &assignment));
+            assert_eq!(maximum, MadarskaMetodaPotentials::new(matrica).solve(Objective::Maximize));
+        }
+    }
+
+    #[test]
+    fn potentials_limits() {
+        let matrica = Matrix::new(vec![
+            vec![4, 6, 5, 3, 1],
+            vec![4, 2, 7, 3, 1],
+            vec![5, 3, 9, 5, 1],
+        ]);
+
+        match MadarskaMetodaPotentials::new(&matrica).solve_with_options(None, &SolveOptions::new().max_iterations(2)) {
+            Err(SolveError::LimitReached { reason, partial, bound }) => {
+                assert_eq!(LimitReason::Iterations, reason);
+                assert_eq!(2, partial.len());
+                assert_eq!(5, bound);
+            },
+            other => panic!("Expected iteration limit, got {:?}", other),
+        }
+    }
+}
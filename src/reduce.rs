@@ -0,0 +1,142 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::limits::SolveError;
+use crate::Matrix;
+
+/// Reductions and minimum searches shared by the solvers. With the `parallel`
+/// feature rows are processed on the rayon thread pool, otherwise in order.
+/// Every change is checked for overflow before the matrix is modified, so a
+/// failed step leaves the matrix as it was.
+impl Matrix {
+    fn row_minima(&self) -> Vec<i32> {
+        #[cfg(feature = "parallel")]
+        let minima = (0..self.rows()).into_par_iter().map(|row| self.find_min_row(row)).collect();
+        #[cfg(not(feature = "parallel"))]
+        let minima = (0..self.rows()).map(|row| self.find_min_row(row)).collect();
+        minima
+    }
+
+    fn column_minima(&self) -> Vec<i32> {
+        #[cfg(feature = "parallel")]
+        let minima = (0..self.columns()).into_par_iter().map(|col| self.find_min_col(col)).collect();
+        #[cfg(not(feature = "parallel"))]
+        let minima = (0..self.columns()).map(|col| self.find_min_col(col)).collect();
+        minima
+    }
+
+    /// Subtracts `amount(row, col)` from every cell, or returns
+    /// `SolveError::Overflow` without changing anything.
+    fn checked_subtract<F: Fn(usize, usize) -> i32 + Sync>(&mut self, amount: F) -> Result<(), SolveError> {
+        let columns = self.columns().max(1);
+        let fits = |(row, values): (usize, &[i32])| {
+            values.iter().enumerate().all(|(col, &v)| v.checked_sub(amount(row, col)).is_some())
+        };
+        #[cfg(feature = "parallel")]
+        let fits = self.data.par_chunks(columns).enumerate().all(fits);
+        #[cfg(not(feature = "parallel"))]
+        let fits = self.data.chunks(columns).enumerate().all(fits);
+        if !fits {
+            return Err(SolveError::Overflow);
+        }
+
+        let apply = |(row, values): (usize, &mut [i32])| {
+            values.iter_mut().enumerate().for_each(|(col, v)| *v -= amount(row, col));
+        };
+        #[cfg(feature = "parallel")]
+        self.data.par_chunks_mut(columns).enumerate().for_each(apply);
+        #[cfg(not(feature = "parallel"))]
+        self.data.chunks_mut(columns).enumerate().for_each(apply);
+        Ok(())
+    }
+
+    pub(crate) fn reduce_rows(&mut self) -> Result<(), SolveError> {
+        let minima = self.row_minima();
+        self.checked_subtract(|row, _| minima[row])
+    }
+
+    pub(crate) fn reduce_columns(&mut self) -> Result<(), SolveError> {
+        let minima = self.column_minima();
+        self.checked_subtract(|_, col| minima[col])
+    }
+
+    /// Hungarian adjustment: `min` is subtracted from uncovered cells and added
+    /// to cells covered by both a crossed row and a crossed column.
+    pub(crate) fn adjust<T: PartialEq + Default + Sync>(&mut self, min: i32, crossed_rows: &[T], crossed_columns: &[T]) -> Result<(), SolveError> {
+        let neg_min = min.checked_neg().ok_or(SolveError::Overflow)?;
+        let crossed = |line: &T| *line != T::default();
+        self.checked_subtract(|row, col| match (crossed(&crossed_rows[row]), crossed(&crossed_columns[col])) {
+            (false, false) => min,
+            (true, true) => neg_min,
+            _ => 0,
+        })
+    }
+
+    /// Smallest value not covered by a crossed row or column, a line counts as
+    /// crossed when its entry differs from `T::default()`.
+    #[cfg(not(feature = "parallel"))]
+    pub(crate) fn uncovered_min<T: PartialEq + Default + Sync>(&self, crossed_rows: &[T], crossed_columns: &[T]) -> Option<i32> {
+        self.iter_rows()
+            .zip(crossed_rows)
+            .filter(|(_, crossed)| **crossed == T::default())
+            .filter_map(|(row, _)| uncovered_row_min(row, crossed_columns))
+            .min()
+    }
+
+    #[cfg(feature = "parallel")]
+    pub(crate) fn uncovered_min<T: PartialEq + Default + Sync>(&self, crossed_rows: &[T], crossed_columns: &[T]) -> Option<i32> {
+        self.data
+            .par_chunks(self.columns().max(1))
+            .zip(crossed_rows)
+            .filter(|(_, crossed)| **crossed == T::default())
+            .filter_map(|(row, _)| uncovered_row_min(row, crossed_columns))
+            .min()
+    }
+}
+
+fn uncovered_row_min<T: PartialEq + Default>(row: &[i32], crossed_columns: &[T]) -> Option<i32> {
+    row.iter()
+        .zip(crossed_columns)
+        .filter(|(_, crossed)| **crossed == T::default())
+        .map(|(&v, _)| v)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reductions() {
+        let mut matrica = Matrix::new(vec![
+            vec![4, 6, 5],
+            vec![7, 2, 9],
+            vec![5, 3, 8],
+        ]);
+
+        matrica.reduce_rows().unwrap();
+        assert_eq!(vec![vec![0, 2, 1], vec![5, 0, 7], vec![2, 0, 5]], matrica.to_vec());
+        matrica.reduce_columns().unwrap();
+        assert_eq!(vec![vec![0, 2, 0], vec![5, 0, 6], vec![2, 0, 4]], matrica.to_vec());
+
+        assert_eq!(Some(0), matrica.uncovered_min(&[0, 0, 0], &[0, 0, 0]));
+        assert_eq!(Some(2), matrica.uncovered_min(&[1, 0, 0], &[0, 1, 0]));
+        assert_eq!(None, matrica.uncovered_min(&[1, 1, 1], &[0, 0, 0]));
+
+        matrica.adjust(2, &[1, 0, 0], &[0, 1, 0]).unwrap();
+        assert_eq!(vec![vec![0, 4, 0], vec![3, 0, 4], vec![0, 0, 2]], matrica.to_vec());
+    }
+
+    #[test]
+    fn overflow_leaves_matrix_unchanged() {
+        let mut matrica = Matrix::new(vec![
+            vec![i32::MAX, i32::MIN],
+            vec![0, 1],
+        ]);
+
+        assert_eq!(Err(SolveError::Overflow), matrica.reduce_rows());
+        assert_eq!(vec![vec![i32::MAX, i32::MIN], vec![0, 1]], matrica.to_vec());
+        assert_eq!(Err(SolveError::Overflow), matrica.adjust(1, &[1, 0], &[1, 0]));
+        assert_eq!(Err(SolveError::Overflow), matrica.adjust(i32::MIN, &[0, 0], &[0, 0]));
+    }
+}
@@ -0,0 +1,351 @@
+use std::convert::TryFrom;
+
+use crate::limits::{SolveError, SolveOptions};
+use crate::{MadarskaMetoda, MadarskaMetodaMunkres, MadarskaMetodaPotentials, Matrix, Objective};
+
+/// Largest matrix the reference solvers accept, enumeration grows with n!.
+pub const MAX_REFERENCE_SIZE: usize = 10;
+
+/// Exact optimum of a small matrix. The cost is kept in `i64` so it is known
+/// even when it does not fit the solvers' `i32` result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceSolution {
+    pub cost: i64,
+    pub assignment: Vec<(usize, usize)>,
+}
+
+struct Search<'a> {
+    matrix: &'a Matrix,
+    objective: Objective,
+    offset: i32,
+    used: Vec<bool>,
+    columns: Vec<usize>,
+    best: Option<(i64, Vec<usize>)>,
+    prune: bool,
+}
+
+impl<'a> Search<'a> {
+    fn new(matrix: &'a Matrix, objective: Objective, prune: bool) -> Self {
+        Self {
+            matrix,
+            objective,
+            offset: matrix.real_max(),
+            used: vec![false; matrix.columns()],
+            columns: Vec::with_capacity(matrix.rows()),
+            best: None,
+            prune,
+        }
+    }
+
+    /// Cost as minimized by the search, the same transform the solvers use.
+    fn cost(&self, row: usize, col: usize) -> i64 {
+        self.objective.cost(self.matrix, self.offset, row, col)
+    }
+
+    /// Cheapest possible completion, every remaining row takes its smallest free cell.
+    fn remaining_bound(&self, row: usize) -> i64 {
+        (row..self.matrix.rows())
+            .map(|r| (0..self.matrix.columns()).filter(|&c| !self.used[c]).map(|c| self.cost(r, c)).min().unwrap_or(0))
+            .sum()
+    }
+
+    fn search(&mut self, row: usize, total: i64) {
+        if row == self.matrix.rows() {
+            if self.best.as_ref().is_none_or(|(best, _)| total < *best) {
+                self.best = Some((total, self.columns.clone()));
+            }
+            return;
+        }
+        if self.prune {
+            if let Some((best, _)) = self.best {
+                if total + self.remaining_bound(row) >= best {
+                    return;
+                }
+            }
+        }
+
+        for col in 0..self.matrix.columns() {
+            if self.used[col] { continue; }
+            self.used[col] = true;
+            self.columns.push(col);
+            self.search(row + 1, total + self.cost(row, col));
+            self.columns.pop();
+            self.used[col] = false;
+        }
+    }
+
+    fn solve(mut self) -> Option<ReferenceSolution> {
+        if self.matrix.rows() > MAX_REFERENCE_SIZE {
+            return None;
+        }
+        self.search(0, 0);
+        let matrix = self.matrix;
+        let (_, columns) = self.best?;
+        let assignment: Vec<_> = columns
+            .into_iter()
+            .enumerate()
+            .filter(|&(row, col)| !matrix.is_dummy(row, col))
+            .collect();
+        Some(ReferenceSolution {
+            cost: assignment.iter().map(|&(row, col)| matrix[row][col] as i64).sum(),
+            assignment,
+        })
+    }
+}
+
+/// Enumerates every permutation, `None` for matrices larger than `MAX_REFERENCE_SIZE`.
+pub fn brute_force<O: Into<Objective>>(matrix: &Matrix, objective: O) -> Option<ReferenceSolution> {
+    let matrix = matrix.clone().into_square();
+    Search::new(&matrix, objective.into(), false).solve()
+}
+
+/// Like `brute_force` but skips partial assignments that cannot beat the best
+/// one found so far.
+pub fn branch_and_bound<O: Into<Objective>>(matrix: &Matrix, objective: O) -> Option<ReferenceSolution> {
+    let matrix = matrix.clone().into_square();
+    Search::new(&matrix, objective.into(), true).solve()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Backend {
+    Classic,
+    Munkres,
+    Potentials,
+}
+
+impl Backend {
+    pub const ALL: [Backend; 3] = [Backend::Classic, Backend::Munkres, Backend::Potentials];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Backend::Classic => "classic",
+            Backend::Munkres => "munkres",
+            Backend::Potentials => "potentials",
+        }
+    }
+
+    /// Solves `matrix` with a new solver of this backend.
+    pub fn solve<O: Into<Objective>>(self, matrix: &Matrix, objective: O, options: &SolveOptions) -> BackendResult {
+        let objective = objective.into();
+        let (result, assignment) = match self {
+            Backend::Classic => {
+                let mut mm = MadarskaMetoda::new(matrix);
+                (mm.solve_with_options(objective, options), mm.assignment())
+            },
+            Backend::Munkres => {
+                let mut mm = MadarskaMetodaMunkres::new(matrix);
+                (mm.solve_with_options(objective, options), mm.assignment())
+            },
+            Backend::Potentials => {
+                let mut mm = MadarskaMetodaPotentials::new(matrix);
+                (mm.solve_with_options(objective, options), mm.assignment())
+            },
+        };
+        BackendResult { backend: self, result, assignment }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackendResult {
+    pub backend: Backend,
+    pub result: Result<i32, SolveError>,
+    pub assignment: Vec<(usize, usize)>,
+}
+
+/// Results of every backend on one matrix together with the reference optimum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossCheck {
+    pub reference: Option<ReferenceSolution>,
+    pub results: Vec<BackendResult>,
+}
+
+impl CrossCheck {
+    /// Backends whose result differs from the reference, or from the
+    /// `Potentials` backend for matrices too large for the reference. A result
+    /// also disagrees when its assignment is not a valid one or does not add
+    /// up to the reported cost.
+    pub fn disagreements(&self, matrix: &Matrix) -> Vec<&BackendResult> {
+        let expected = match &self.reference {
+            Some(reference) => Some(reference.cost),
+            None => self
+                .results
+                .iter()
+                .find(|r| r.backend == Backend::Potentials)
+                .and_then(|r| r.result.as_ref().ok())
+                .map(|&cost| cost as i64),
+        };
+
+        self.results
+            .iter()
+            .filter(|r| match (&r.result, expected) {
+                (Ok(cost), Some(expected)) => {
+                    *cost as i64 != expected
+                        || !is_valid_assignment(matrix, &r.assignment)
+                        || r.assignment.iter().map(|&(row, col)| matrix[row][col] as i64).sum::<i64>() != expected
+                },
+                (Err(SolveError::Overflow), Some(expected)) => i32::try_from(expected).is_ok(),
+                (Ok(_), None) => false,
+                (Err(_), _) => true,
+            })
+            .collect()
+    }
+
+    pub fn is_consistent(&self, matrix: &Matrix) -> bool {
+        self.disagreements(matrix).is_empty()
+    }
+}
+
+/// Every real row and column is used at most once and as many cells are
+/// assigned as the smaller side of the original matrix.
+pub fn is_valid_assignment(matrix: &Matrix, assignment: &[(usize, usize)]) -> bool {
+    let mut rows = vec![false; matrix.rows()];
+    let mut columns = vec![false; matrix.columns()];
+    for &(row, col) in assignment {
+        if row >= matrix.rows() || col >= matrix.columns() || matrix.is_dummy(row, col) || rows[row] || columns[col] {
+            return false;
+        }
+        rows[row] = true;
+        columns[col] = true;
+    }
+    assignment.len() == matrix.original_rows().min(matrix.original_columns())
+}
+
+/// Runs every backend on `matrix` and the exact reference when the matrix is
+/// small enough. Backends are stopped after `n³ + 100` iterations, so a solver
+/// that loops forever shows up as a limit error.
+pub fn cross_check<O: Into<Objective>>(matrix: &Matrix, objective: O) -> CrossCheck {
+    let objective = objective.into();
+    let options = SolveOptions::new().max_iterations(matrix.rows().max(matrix.columns()).pow(3) + 100);
+
+    CrossCheck {
+        reference: branch_and_bound(matrix, objective),
+        results: Backend::ALL.iter().map(|backend| backend.solve(matrix, objective, &options)).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::batch::solve_batch;
+    use proptest::prelude::*;
+    use std::ops::Range;
+
+    #[test]
+    fn reference_solvers() {
+        let matrica = Matrix::new(vec![
+            vec![82, 83, 69, 92, 71, 35],
+            vec![77, 37, 49, 92, 18, 64],
+            vec![11, 69,  5, 86, 73, 40],
+            vec![ 8,  9, 98, 23, 57, 13],
+            vec![64, 52, 33, 17, 66, 24],
+            vec![90, 14, 61, 44,  8, 55],
+        ]);
+
+        for objective in [Objective::Minimize, Objective::Maximize] {
+            let exact = brute_force(&matrica, objective).unwrap();
+            let pruned = branch_and_bound(&matrica, objective).unwrap();
+            assert_eq!(exact.cost, pruned.cost);
+            assert!(is_valid_assignment(&matrica, &pruned.assignment));
+        }
+        assert_eq!(None, brute_force(&Matrix::new_empty(11, 11), None));
+
+        let rectangular = Matrix::new(vec![vec![4, 6, 5, 3, 1], vec![4, 2, 7, 3, 1], vec![5, 3, 9, 5, 1]]);
+        let solution = branch_and_bound(&rectangular, None).unwrap();
+        assert_eq!(6, solution.cost);
+        assert_eq!(3, solution.assignment.len());
+    }
+
+    #[test]
+    fn cross_check_backends() {
+        let matrica = Matrix::new(vec![
+            vec![10, 8,  4, 5],
+            vec![ 6, 2, 12, 3],
+            vec![ 3, 5,  6, 9],
+            vec![ 4, 7,  8, 6],
+        ]);
+
+        let check = cross_check(&matrica, None);
+        assert_eq!(Some(15), check.reference.as_ref().map(|r| r.cost));
+        assert_eq!(3, check.results.len());
+        assert!(check.is_consistent(&matrica));
+
+        let mut wrong = check.clone();
+        wrong.results[1].result = Ok(16);
+        wrong.results[2].result = Ok(-1);
+        let backends: Vec<Backend> = wrong.disagreements(&matrica).iter().map(|r| r.backend).collect();
+        assert_eq!(vec![Backend::Munkres, Backend::Potentials], backends);
+
+        assert!(!is_valid_assignment(&matrica, &[(0, 0), (1, 0), (2, 2), (3, 3)]));
+    }
+
+    fn matrix(rows: Range<usize>, columns: Range<usize>, values: Range<i32>) -> impl Strategy<Value = Matrix> {
+        (rows, columns).prop_flat_map(move |(rows, columns)| {
+            prop::collection::vec(prop::collection::vec(values.clone(), columns), rows).prop_map(Matrix::new)
+        })
+    }
+
+    fn unpadded(rows: Range<usize>, columns: Range<usize>, values: Range<i32>) -> impl Strategy<Value = Matrix> {
+        (rows, columns).prop_flat_map(move |(rows, columns)| {
+            prop::collection::vec(prop::collection::vec(values.clone(), columns), rows).prop_map(Matrix::new_rectangular)
+        })
+    }
+
+    fn square(sizes: Range<usize>, values: Range<i32>) -> impl Strategy<Value = Matrix> {
+        sizes.prop_flat_map(move |n| matrix(n..n + 1, n..n + 1, values.clone()))
+    }
+
+    /// Every backend finds the reference optimum with a valid assignment that adds up to it.
+    fn backends_agree(matrix: &Matrix, objective: Objective) -> Result<(), TestCaseError> {
+        let check = cross_check(matrix, objective);
+        let reference = check.reference.clone().unwrap();
+        prop_assert!(is_valid_assignment(matrix, &reference.assignment));
+
+        for result in &check.results {
+            prop_assert_eq!(&Ok(reference.cost as i32), &result.result, "{:?}", result.backend);
+            prop_assert!(is_valid_assignment(matrix, &result.assignment), "{:?} {:?}", result.backend, result.assignment);
+            let total: i64 = result.assignment.iter().map(|&(row, col)| matrix[row][col] as i64).sum();
+            prop_assert_eq!(reference.cost, total, "{:?}", result.backend);
+        }
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn square_matrices(matrica in square(1..7, -50..50), maximize in any::<bool>()) {
+            backends_agree(&matrica, maximize.into())?;
+        }
+
+        #[test]
+        fn rectangular_matrices(
+            matrica in matrix(1..7, 1..7, -50..50).prop_filter("rectangular", |m| m.original_rows() != m.original_columns()),
+            maximize in any::<bool>(),
+        ) {
+            backends_agree(&matrica, maximize.into())?;
+        }
+
+        #[test]
+        fn unpadded_matrices(matrica in unpadded(1..7, 1..7, -50..50), maximize in any::<bool>()) {
+            backends_agree(&matrica, maximize.into())?;
+            let expected = brute_force(&matrica, maximize).unwrap().cost as i32;
+            let batch = solve_batch(std::slice::from_ref(&matrica), maximize);
+            prop_assert_eq!(Ok(expected), batch[0].result.clone());
+        }
+
+        #[test]
+        fn tied_matrices(matrica in square(1..8, 0..3), maximize in any::<bool>()) {
+            backends_agree(&matrica, maximize.into())?;
+        }
+
+        #[test]
+        fn negative_matrices(matrica in matrix(1..7, 1..7, -1000..0), maximize in any::<bool>()) {
+            backends_agree(&matrica, maximize.into())?;
+        }
+
+        #[test]
+        fn reference_solvers_agree(matrica in matrix(1..7, 1..7, -20..20), maximize in any::<bool>()) {
+            let exact = brute_force(&matrica, maximize).unwrap();
+            let pruned = branch_and_bound(&matrica, maximize).unwrap();
+            prop_assert_eq!(exact.cost, pruned.cost);
+        }
+    }
+}
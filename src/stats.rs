@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Phase {
+    Reduction,
+    Assignment,
+    Covering,
+    Adjustment,
+}
+
+/// Counters and timings collected while solving.
+/// `augmentations` counts augmenting paths, in `MadarskaMetoda` only those
+/// that complete the greedy assignment. `peak_matrix_size` is the cell count
+/// of the largest working matrix.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SolveStats {
+    pub elapsed: Duration,
+    pub reduction_time: Duration,
+    pub assignment_time: Duration,
+    pub covering_time: Duration,
+    pub adjustment_time: Duration,
+    pub reductions: usize,
+    pub augmentations: usize,
+    pub covering_iterations: usize,
+    pub peak_matrix_size: usize,
+}
+
+impl SolveStats {
+    pub(crate) fn record(&mut self, phase: Phase, duration: Duration) {
+        match phase {
+            Phase::Reduction => self.reduction_time += duration,
+            Phase::Assignment => self.assignment_time += duration,
+            Phase::Covering => self.covering_time += duration,
+            Phase::Adjustment => self.adjustment_time += duration,
+        }
+    }
+
+    pub(crate) fn track_matrix(&mut self, rows: usize, columns: usize) {
+        self.peak_matrix_size = self.peak_matrix_size.max(rows * columns);
+    }
+}
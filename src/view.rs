@@ -0,0 +1,200 @@
+use crate::Matrix;
+
+/// Rows and columns of a `Matrix` picked by index, optionally transposed.
+/// Building and narrowing a view borrows the matrix, solvers accept a view in
+/// place of a matrix but copy it with `to_matrix` first. `original_assignment`
+/// maps their result back to the indices of the viewed matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixView<'a> {
+    matrix: &'a Matrix,
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+    transposed: bool,
+}
+
+impl Matrix {
+    /// View of every original row and column, padded dummy lines are left out.
+    pub fn view(&self) -> MatrixView<'_> {
+        MatrixView {
+            matrix: self,
+            rows: (0..self.original_rows()).collect(),
+            columns: (0..self.original_columns()).collect(),
+            transposed: false,
+        }
+    }
+
+    /// View with the roles of rows and columns swapped.
+    pub fn transposed(&self) -> MatrixView<'_> {
+        self.view().transpose()
+    }
+
+    /// View of the given original rows and columns, in the given order.
+    pub fn submatrix(&self, rows: &[usize], columns: &[usize]) -> MatrixView<'_> {
+        self.view().subset(rows, columns)
+    }
+}
+
+impl<'a> MatrixView<'a> {
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Row and column of the viewed matrix behind a cell of the view.
+    pub fn original_index(&self, row: usize, col: usize) -> (usize, usize) {
+        let (row, col) = (self.rows[row], self.columns[col]);
+        if self.transposed { (col, row) } else { (row, col) }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> i32 {
+        let (row, col) = self.original_index(row, col);
+        self.matrix[row][col]
+    }
+
+    pub fn transpose(mut self) -> Self {
+        std::mem::swap(&mut self.rows, &mut self.columns);
+        self.transposed = !self.transposed;
+        self
+    }
+
+    /// Narrows the view to some of its own rows and columns, indices refer to
+    /// this view and not to the viewed matrix. Panics on an index out of range
+    /// or one picked twice, a line can be assigned only once.
+    pub fn subset(&self, rows: &[usize], columns: &[usize]) -> Self {
+        assert!(distinct_below(rows, self.rows()), "Row indices must be distinct and in range");
+        assert!(distinct_below(columns, self.columns()), "Column indices must be distinct and in range");
+        Self {
+            matrix: self.matrix,
+            rows: rows.iter().map(|&row| self.rows[row]).collect(),
+            columns: columns.iter().map(|&col| self.columns[col]).collect(),
+            transposed: self.transposed,
+        }
+    }
+
+    /// Maps an assignment of a solver given this view to the viewed matrix.
+    pub fn original_assignment(&self, assignment: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        assignment.iter().map(|&(row, col)| self.original_index(row, col)).collect()
+    }
+
+    /// Labels of the picked rows, or columns, taken from the viewed matrix.
+    fn labels(&self, indices: &[usize], rows: bool) -> Option<Vec<String>> {
+        let labels = if rows != self.transposed { &self.matrix.row_labels } else { &self.matrix.column_labels };
+        labels.as_ref().map(|labels| indices.iter().map(|&i| labels[i].clone()).collect())
+    }
+
+    /// Copies the viewed values into a matrix padded like `Matrix::new`,
+    /// together with the labels of the picked lines.
+    pub fn to_matrix(&self) -> Matrix {
+        let data = (0..self.rows())
+            .map(|row| (0..self.columns()).map(|col| self.get(row, col)).collect())
+            .collect();
+        let mut matrix = Matrix::new(data);
+        matrix.row_labels = self.labels(&self.rows, true);
+        matrix.column_labels = self.labels(&self.columns, false);
+        matrix
+    }
+}
+
+fn distinct_below(indices: &[usize], len: usize) -> bool {
+    let mut seen = vec![false; len];
+    indices.iter().all(|&i| i < len && !std::mem::replace(&mut seen[i], true))
+}
+
+impl From<&MatrixView<'_>> for Matrix {
+    fn from(view: &MatrixView<'_>) -> Self {
+        view.to_matrix()
+    }
+}
+
+impl From<MatrixView<'_>> for Matrix {
+    fn from(view: MatrixView<'_>) -> Self {
+        view.to_matrix()
+    }
+}
+
+impl From<&Matrix> for Matrix {
+    fn from(matrix: &Matrix) -> Self {
+        matrix.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reference;
+    use crate::{MadarskaMetoda, MadarskaMetodaMunkres, MadarskaMetodaPotentials};
+
+    #[test]
+    fn transpose_and_submatrix() {
+        let matrica = Matrix::new(vec![
+            vec![4, 6, 5, 3, 1],
+            vec![4, 2, 7, 3, 1],
+            vec![5, 3, 9, 5, 1],
+        ]);
+
+        let transposed = matrica.transposed();
+        assert_eq!((5, 3), (transposed.rows(), transposed.columns()));
+        assert_eq!(7, transposed.get(2, 1));
+        assert_eq!((1, 2), transposed.original_index(2, 1));
+        assert_eq!(matrica.view().to_matrix(), transposed.clone().transpose().to_matrix());
+
+        let sub = matrica.submatrix(&[2, 0], &[4, 1, 2]);
+        assert_eq!(vec![vec![1, 3, 9], vec![1, 6, 5], vec![0, 0, 0]], sub.to_matrix().to_vec());
+        let nested = sub.subset(&[1], &[2, 0]).transpose();
+        assert_eq!(vec![vec![5, 0], vec![1, 0]], nested.to_matrix().to_vec());
+        assert_eq!(vec![(0, 2), (0, 4)], nested.original_assignment(&[(0, 0), (1, 0)]));
+
+        let labelled = matrica.clone().with_labels(
+            vec!["A".into(), "B".into(), "C".into()],
+            vec!["a".into(), "b".into(), "c".into(), "d".into(), "e".into()],
+        ).unwrap();
+        let view = labelled.submatrix(&[1, 2], &[3, 0]).transpose().to_matrix();
+        assert_eq!(Some("d"), view.row_label(0));
+        assert_eq!(Some("C"), view.column_label(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Row indices must be distinct")]
+    fn subset_duplicate_rows() {
+        Matrix::new(vec![vec![1, 2], vec![3, 4]]).submatrix(&[0, 0], &[0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column indices must be distinct")]
+    fn subset_duplicate_columns() {
+        Matrix::new(vec![vec![1, 2], vec![3, 4]]).transposed().subset(&[0, 1], &[1, 1]);
+    }
+
+    #[test]
+    fn solve_views() {
+        let matrica = Matrix::new(vec![
+            vec![82, 83, 69, 92, 71, 35],
+            vec![77, 37, 49, 92, 18, 64],
+            vec![11, 69,  5, 86, 73, 40],
+            vec![ 8,  9, 98, 23, 57, 13],
+            vec![64, 52, 33, 17, 66, 24],
+            vec![90, 14, 61, 44,  8, 55],
+        ]);
+
+        let transposed = matrica.transposed();
+        let expected = MadarskaMetodaPotentials::new(&matrica).solve(None);
+        let mut mm = MadarskaMetodaMunkres::new(&transposed);
+        assert_eq!(expected, mm.solve(None));
+        let assignment = transposed.original_assignment(&mm.assignment());
+        assert!(reference::is_valid_assignment(&matrica, &assignment));
+        assert_eq!(expected, assignment.iter().map(|&(row, col)| matrica[row][col]).sum::<i32>());
+
+        let depot = matrica.submatrix(&[0, 3, 5], &[1, 2, 3, 4]);
+        let exact = reference::brute_force(&depot.to_matrix(), None).unwrap();
+        let mut classic = MadarskaMetoda::new(&depot);
+        assert_eq!(exact.cost as i32, classic.solve(None));
+        let mut potentials = MadarskaMetodaPotentials::new(depot.clone());
+        assert_eq!(exact.cost as i32, potentials.solve(None));
+        for (row, col) in depot.original_assignment(&potentials.assignment()) {
+            assert!([0, 3, 5].contains(&row) && [1, 2, 3, 4].contains(&col));
+        }
+    }
+}